@@ -0,0 +1,70 @@
+//! Generate the element and attribute lookup tables from htmf's `spec/html.txt`.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+#[path = "../htmf/spec/parse.rs"]
+mod parse;
+
+use parse::AttributeKind;
+
+const SPEC: &str = "../htmf/spec/html.txt";
+
+fn main() {
+    println!("cargo:rerun-if-changed={SPEC}");
+    println!("cargo:rerun-if-changed=../htmf/spec/parse.rs");
+
+    let source = fs::read_to_string(SPEC).expect("htmf/spec/html.txt should be readable");
+    let spec = parse::parse("html.txt", &source);
+
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        "/// The htmf function for the element `tag`.\nfn element_function(tag: &str) -> Option<&'static str> {{\n    match tag {{"
+    );
+    for element in &spec.elements {
+        let _ = writeln!(
+            out,
+            "        {:?} => Some({:?}),",
+            element.name, element.function
+        );
+    }
+    let _ = writeln!(out, "        _ => None,\n    }}\n}}\n");
+
+    let _ = writeln!(
+        out,
+        "/// Whether `tag` is a void element, which can't have children.\nfn is_void_element(tag: &str) -> bool {{\n    matches!(\n        tag,"
+    );
+    let void: Vec<_> = spec
+        .elements
+        .iter()
+        .filter(|element| element.void)
+        .map(|element| format!("{:?}", element.name))
+        .collect();
+    let _ = writeln!(out, "        {}\n    )\n}}\n", void.join(" | "));
+
+    let _ = writeln!(
+        out,
+        "/// The htmf function for the attribute `name`, how its value is passed,\n/// and the elements it applies to, or `None` for global attributes.\n#[allow(clippy::type_complexity)]\nfn attribute_function(name: &str) -> Option<(&'static str, AttrKind, Option<&'static [&'static str]>)> {{\n    match name {{"
+    );
+    for attribute in &spec.attributes {
+        let elements = match &attribute.elements {
+            Some(elements) => format!("Some(&{elements:?})"),
+            None => "None".to_string(),
+        };
+        let kind = match (&attribute.value_type, attribute.kind) {
+            (Some(value_type), _) => format!("AttrKind::Typed({value_type:?})"),
+            (None, AttributeKind::Bool) => "AttrKind::Bool".to_string(),
+            (None, _) => "AttrKind::Value".to_string(),
+        };
+        let _ = writeln!(
+            out,
+            "        {:?} => Some(({:?}, {kind}, {elements})),",
+            attribute.name, attribute.function
+        );
+    }
+    let _ = writeln!(out, "        _ => None,\n    }}\n}}");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set by cargo");
+    fs::write(Path::new(&out_dir).join("html.rs"), out).expect("html.rs should be writable");
+}
@@ -6,6 +6,9 @@ use tl::{HTMLTag, Node, Parser};
 #[cfg(test)]
 mod tests;
 
+// `element_function`, `is_void_element` and `attribute_function`, generated by `build.rs` from htmf's `spec/html.txt`.
+include!(concat!(env!("OUT_DIR"), "/html.rs"));
+
 #[derive(clap::Parser)]
 struct Args {
     input: PathBuf,
@@ -24,17 +27,20 @@ fn main() -> Result<()> {
 
 fn convert_file(Args { input, inline }: &Args) -> anyhow::Result<String> {
     let contents = std::fs::read_to_string(input)?;
+    convert_source(&contents, *inline)
+}
 
-    let ast = tl::parse(&contents, tl::ParserOptions::default())?;
+fn convert_source(contents: &str, inline: bool) -> anyhow::Result<String> {
+    let ast = tl::parse(contents, tl::ParserOptions::default())?;
     let parser = ast.parser();
 
-    let mut result = if *inline {
+    let mut result = if inline {
         "use htmf::prelude_inline::*;\n\npub fn view(data: &Data) -> Element {".to_string()
     } else {
         "use htmf::prelude::*;\n\npub fn view(data: &Data) -> Element {".to_string()
     };
     if ast.children().len() > 1 {
-        if *inline {
+        if inline {
             result.push_str("fragment([");
         } else {
             result.push_str("fragment().with([");
@@ -44,7 +50,7 @@ fn convert_file(Args { input, inline }: &Args) -> anyhow::Result<String> {
         let Some(child) = child.get(parser) else {
             continue;
         };
-        if let Some(cc) = convert_node(child, parser, ast.children().len() == 1, *inline)? {
+        if let Some(cc) = convert_node(child, parser, ast.children().len() == 1, inline)? {
             result.push_str(&cc);
             result.push_str(",\n");
         }
@@ -99,7 +105,7 @@ fn convert_tag(tag: &HTMLTag, parser: &Parser, inline: bool) -> Result<String> {
     sorted_attributes.sort();
 
     for (key, val) in sorted_attributes {
-        let attr_fn = convert_attr(&key, val.as_deref());
+        let attr_fn = convert_attr(&tag.name().as_utf8_str(), &key, val.as_deref());
         attr_code.push_str(&attr_fn);
         if many_attrs {
             attr_code.push(',');
@@ -138,84 +144,72 @@ fn convert_tag(tag: &HTMLTag, parser: &Parser, inline: bool) -> Result<String> {
         }
     }
 
-    let code = if inline {
-        format!("{}({attr_code}, {child_code})", tag.name().as_utf8_str())
-    } else {
-        format!("{}({attr_code}){child_code}", tag.name().as_utf8_str())
+    if attr_code.is_empty() {
+        attr_code.push_str("()");
+    }
+    let name = tag.name().as_utf8_str();
+    let function = match element_function(&name) {
+        Some(function) => function.to_string(),
+        None => {
+            attr_code = format!("\"{name}\", {attr_code}");
+            "custom_tag".to_string()
+        }
+    };
+    let code = match (is_void_element(&name), inline) {
+        // Void tag functions only take attributes.
+        (true, _) => format!("{function}({attr_code})"),
+        (false, true) => format!("{function}({attr_code}, {child_code})"),
+        (false, false) => format!("{function}({attr_code}){child_code}"),
     };
 
     Ok(code)
 }
 
-fn convert_attr(name: &str, value: Option<&str>) -> String {
-    let known = match name {
-        "accept" => Some("accept"),
-        "accept_charset" => Some("accept_charset"),
-        "action" => Some("action"),
-        "alt" => Some("alt"),
-        "aria_checked" => Some("aria_checked"),
-        "aria_current" => Some("aria_current"),
-        "aria_disabled" => Some("aria_disabled"),
-        "aria_hidden" => Some("aria_hidden"),
-        "aria_invalid" => Some("aria_invalid"),
-        "aria_label" => Some("aria_label"),
-        "aria_labelledby" => Some("aria_labelledby"),
-        "aria_placeholder" => Some("aria_placeholder"),
-        "aria_readonly" => Some("aria_readonly"),
-        "aria_required" => Some("aria_required"),
-        "async" => Some("async_"),
-        "autocapitalize" => Some("autocapitalize"),
-        "autocomplete" => Some("autocomplete"),
-        "autofocus" => Some("autofocus"),
-        "autoplay" => Some("autoplay"),
-        "capture" => Some("capture"),
-        "charset" => Some("charset"),
-        "checked" => Some("checked"),
-        "cite_attr" => Some("cite_attr"),
-        "class" => Some("class"),
-        "content" => Some("content"),
-        "contenteditable" => Some("contenteditable"),
-        "crossorigin" => Some("crossorigin"),
-        "defer" => Some("defer"),
-        "disabled" => Some("disabled"),
-        "draggable" => Some("draggable"),
-        "enctype" => Some("enctype"),
-        "for" => Some("for_"),
-        "formaction" => Some("formaction"),
-        "height" => Some("height"),
-        "href" => Some("href"),
-        "http_equiv" => Some("http_equiv"),
-        "id" => Some("id"),
-        "integrity" => Some("integrity"),
-        "lang" => Some("lang"),
-        "loop" => Some("loop_"),
-        "maxlength" => Some("maxlength"),
-        "method" => Some("method"),
-        "minlength" => Some("minlength"),
-        "name" => Some("name"),
-        "placeholder" => Some("placeholder"),
-        "preload" => Some("preload"),
-        "property" => Some("property"),
-        "readonly" => Some("readonly"),
-        "rel" => Some("rel"),
-        "required" => Some("required"),
-        "role" => Some("role"),
-        "selected" => Some("selected"),
-        "src" => Some("src"),
-        "style" => Some("style"),
-        "tabindex" => Some("tabindex"),
-        "target" => Some("target"),
-        "title_attr" => Some("title"),
-        "type" => Some("type_"),
-        "value" => Some("value"),
-        "width" => Some("width"),
+/// How `convert_attr` passes the value of an attribute to its function.
+enum AttrKind {
+    /// A boolean attribute, converted to `function(true)`.
+    Bool,
+    /// Any other HTML or untyped ARIA attribute, whose value is passed as a string.
+    Value,
+    /// An ARIA attribute whose value is converted into this type, like `bool` or `Tristate`.
+    Typed(&'static str),
+}
+
+/// Convert the attribute `name` of the element `tag`.
+/// Attributes that the element doesn't accept are converted to `attr` calls, so the code compiles,
+/// and so are typed ARIA values that can't be written as a literal of their type.
+fn convert_attr(tag: &str, name: &str, value: Option<&str>) -> String {
+    let val = value.unwrap_or("");
+    let accepted = |elements: Option<&[&str]>| {
+        element_function(tag).is_none() || elements.is_none_or(|elements| elements.contains(&tag))
+    };
+    let code = match attribute_function(name) {
+        Some((function, kind, elements)) if accepted(elements) => match kind {
+            AttrKind::Bool => Some(format!("{function}(true)")),
+            AttrKind::Value => Some(format!("{function}(\"{val}\")")),
+            AttrKind::Typed(value_type) => {
+                typed_literal(value_type, val).map(|literal| format!("{function}({literal})"))
+            }
+        },
         _ => None,
     };
+    code.unwrap_or_else(|| format!("attr(\"{name}\", \"{val}\")"))
+}
 
-    let val = value.unwrap_or("");
-    if let Some(name) = known {
-        format!("{name}(\"{val}\")")
-    } else {
-        format!("attr(\"{name}\", \"{val}\")")
-    }
+/// The Rust literal for `val` as a value of the ARIA type `value_type`, if there is one.
+/// Token enums like `Tristate` and `Role` can't be built from strings, so they have none.
+fn typed_literal(value_type: &str, val: &str) -> Option<String> {
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let unsigned = val.strip_prefix('-').unwrap_or(val);
+    let literal = match value_type {
+        "bool" => val == "true" || val == "false",
+        "i32" => is_digits(unsigned) && val.parse::<i32>().is_ok(),
+        "f64" => match unsigned.split_once('.') {
+            Some((whole, fraction)) => is_digits(whole) && is_digits(fraction),
+            None => is_digits(unsigned),
+        },
+        "IdRefs" => return Some(format!("\"{val}\"")),
+        _ => false,
+    };
+    literal.then(|| val.to_string())
 }
@@ -1 +1,70 @@
 mod login_page;
+
+use crate::{convert_attr, convert_source, element_function, is_void_element};
+
+#[test]
+fn spec_names() {
+    assert_eq!(element_function("main"), Some("main_"));
+    assert_eq!(element_function("my-widget"), None);
+    assert_eq!(
+        convert_attr("a", "title", Some("Home")),
+        r#"title_attr("Home")"#
+    );
+    assert_eq!(
+        convert_attr("form", "accept-charset", Some("utf-8")),
+        r#"accept_charset("utf-8")"#
+    );
+    assert_eq!(
+        convert_attr("iframe", "allowfullscreen", None),
+        "allowfullscreen(true)"
+    );
+    assert_eq!(
+        convert_attr("button", "aria-label", Some("Close")),
+        r#"aria_label("Close")"#
+    );
+    assert_eq!(
+        convert_attr("div", "aria-labelledby", Some("title subtitle")),
+        r#"aria_labelledby("title subtitle")"#
+    );
+    assert_eq!(
+        convert_attr("div", "aria-hidden", Some("true")),
+        "aria_hidden(true)"
+    );
+    assert_eq!(convert_attr("h2", "aria-level", Some("2")), "aria_level(2)");
+    assert_eq!(
+        convert_attr("div", "aria-valuenow", Some("-0.5")),
+        "aria_valuenow(-0.5)"
+    );
+    assert_eq!(
+        convert_attr("div", "aria-valuenow", Some("1e3")),
+        r#"attr("aria-valuenow", "1e3")"#
+    );
+    assert_eq!(
+        convert_attr("div", "aria-checked", Some("mixed")),
+        r#"attr("aria-checked", "mixed")"#
+    );
+    assert_eq!(
+        convert_attr("div", "role", Some("dialog")),
+        r#"attr("role", "dialog")"#
+    );
+    assert_eq!(
+        convert_attr("div", "href", Some("/")),
+        r#"attr("href", "/")"#
+    );
+    assert_eq!(convert_attr("my-link", "href", Some("/")), r#"href("/")"#);
+}
+
+#[test]
+fn void_elements() {
+    assert!(is_void_element("input"));
+    assert!(!is_void_element("p"));
+    let source = r#"<p>One<br>Two<input type="text"></p>"#;
+    assert_eq!(
+        convert_source(source, true).unwrap(),
+        "use htmf::prelude_inline::*;\n\npub fn view(data: &Data) -> Element {p((), [text(\"One\"),\nbr(()),\ntext(\"Two\"),\ninput(type_(\"text\")),\n]),\n"
+    );
+    assert_eq!(
+        convert_source(source, false).unwrap(),
+        "use htmf::prelude::*;\n\npub fn view(data: &Data) -> Element {p(()).with([text(\"One\"),\nbr(()),\ntext(\"Two\"),\ninput(type_(\"text\")),\n]),\n"
+    );
+}
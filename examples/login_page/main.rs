@@ -29,7 +29,7 @@ pub struct Credentials {
 
 fn base(children: Vec<Element>) -> Element {
     html(class("w-full h-full")).with([
-        head([]).with([
+        head([]).with((
             link([rel("stylesheet"), href("/assets/preflight.css")]),
             link([rel("stylesheet"), href("/assets/railwind.css")]),
             script(src("/assets/htmx.1.9.9.js")),
@@ -38,7 +38,7 @@ fn base(children: Vec<Element>) -> Element {
                 name("viewport"),
                 content("width=device-width,initial-scale=1"),
             ]),
-        ]),
+        )),
         body(class("w-full h-full text-gray-200 bg-neutral-800")).with(children),
     ])
 }
@@ -60,43 +60,46 @@ fn login(errors: FormErrors, credentials: Credentials) -> Element {
              justify-center disabled:bg-neutral-500 text-neutral-900",
         ),
     ])
-    .with([
-        text("Sign in").with(span(class("inline-block w-09 h-4")).with(span(class(
+    .with((
+        text("Sign in"),
+        span(class("inline-block w-09 h-4")).with(span(class(
             "block w-4 h-4 -ml-6 border-2 rounded-full border-neutral-900 animate-spin \
              border-t-transparent htmx-indicator",
-        )))),
-    ]);
+        ))),
+    ));
 
-    let form_fields = [
+    let form_fields = (
         h1(class("text-2xl font-bold tracking-tight text-center"))
             .with(text("Sign in to your account")),
         label([
             class("mt-10 text-neutral-400"),
-            name("credentials[username]"),
+            for_("username"),
         ])
         .with(text("Username")),
         errors_fragment(&errors, "username"),
         input([
             type_("text"),
+            id("username"),
             name("credentials[username]"),
             class("rounded py-1.5 px-3 mt-2 bg-neutral-900"),
             value(credentials.username),
-            required("true"),
+            required(true),
         ]),
         label([
             class("mt-4 text-neutral-400"),
-            name("credentials[password]"),
+            for_("password"),
         ])
         .with(text("Password")),
         errors_fragment(&errors, "password"),
         input([
             type_("password"),
+            id("password"),
             name("credentials[password]"),
             class("rounded py-1.5 px-3 mt-2 bg-neutral-900"),
-            required("true"),
+            required(true),
         ]),
         errors_fragment(&errors, "root"),
-    ];
+    );
 
     base(
         [form([
@@ -7,7 +7,7 @@ fn run(c: &mut Criterion) {
         b.iter(|| {
             html(class("w-full h-full"))
                 .with([
-                    head([]).with([
+                    head([]).with((
                         link([rel("stylesheet"), href("/assets/preflight.css")]),
                         link([rel("stylesheet"), href("/assets/railwind.css")]),
                         script(src("/assets/htmx.1.9.9.js")),
@@ -16,7 +16,7 @@ fn run(c: &mut Criterion) {
                             name("viewport"),
                             content("width=device-width,initial-scale=1"),
                         ]),
-                    ]),
+                    )),
                     body(class("w-full h-full text-gray-200 bg-neutral-800")),
                 ])
                 .to_html();
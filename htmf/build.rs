@@ -0,0 +1,368 @@
+//! Generate the element and attribute macros and marker types from the tables in `spec/`.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+#[path = "spec/parse.rs"]
+mod parse;
+
+use parse::{type_name, AttributeKind, Spec};
+
+/// A table of elements from another namespace that can be used inside HTML, like `svg.txt`.
+struct Foreign {
+    /// The name of the table and the prefix of the generated macros, like `svg`.
+    name: &'static str,
+    /// The name used in doc comments, like `SVG`.
+    title: &'static str,
+    /// The trait implemented by the element marker types, like `SvgElementKind`.
+    kind_trait: &'static str,
+}
+
+const FOREIGN: [Foreign; 2] = [
+    Foreign {
+        name: "svg",
+        title: "SVG",
+        kind_trait: "SvgElementKind",
+    },
+    Foreign {
+        name: "mathml",
+        title: "MathML",
+        kind_trait: "MathMlElementKind",
+    },
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=spec/html.txt");
+    println!("cargo:rerun-if-changed=spec/parse.rs");
+
+    let html = read_spec("html");
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set by cargo");
+    let write = |file: &str, contents: String| {
+        fs::write(Path::new(&out_dir).join(file), contents)
+            .unwrap_or_else(|e| panic!("{file} should be writable: {e}"));
+    };
+
+    write("html.rs", macros(&html));
+    write("spec.rs", marker_types(&html));
+
+    for foreign in &FOREIGN {
+        println!("cargo:rerun-if-changed=spec/{}.txt", foreign.name);
+        let spec = read_spec(foreign.name);
+        write(
+            &format!("{}.rs", foreign.name),
+            foreign_macros(&html, &spec, foreign),
+        );
+        write(
+            &format!("spec_{}.rs", foreign.name),
+            foreign_marker_types(&html, &spec, foreign),
+        );
+    }
+}
+
+fn read_spec(name: &str) -> Spec {
+    let file = format!("{name}.txt");
+    let source = fs::read_to_string(format!("spec/{file}"))
+        .unwrap_or_else(|e| panic!("spec/{file} should be readable: {e}"));
+    parse::parse(&file, &source)
+}
+
+fn macros(spec: &Spec) -> String {
+    let mut out = String::new();
+
+    // `html_elements!(m)` calls `m!(function, Marker)`, `m!(function, Marker, leaf)`,
+    // `m!(function, Marker, "tag")` or `m!(function, Marker, "tag", leaf)` for every element.
+    let _ = writeln!(out, "macro_rules! html_elements {{\n    ($m:ident) => {{");
+    for element in &spec.elements {
+        let _ = writeln!(
+            out,
+            "        $m!({});",
+            tag_macro_args(&element.function, &element.name, element.void)
+        );
+    }
+    let _ = writeln!(out, "    }};\n}}\n");
+
+    // `html_element_methods!(m)` does the same with the builder method names.
+    let _ = writeln!(
+        out,
+        "#[cfg(feature = \"unstable-builder\")]\nmacro_rules! html_element_methods {{\n    ($m:ident) => {{"
+    );
+    for element in &spec.elements {
+        let _ = writeln!(
+            out,
+            "        $m!({});",
+            tag_macro_args(&element.method, &element.name, element.void)
+        );
+    }
+    let _ = writeln!(out, "    }};\n}}\n");
+
+    // `html_attributes!(m)` calls `m!(function, "name", Marker)`,
+    // `m!(function, "name", Marker, bool)` or `m!(function, "name", Marker, url)` for every attribute.
+    let _ = writeln!(out, "macro_rules! html_attributes {{\n    ($m:ident) => {{");
+    for attribute in spec.attributes.iter().filter(|a| !a.aria) {
+        let kind = match attribute.kind {
+            AttributeKind::Value => "",
+            AttributeKind::Bool => ", bool",
+            AttributeKind::Url => ", url",
+        };
+        let _ = writeln!(
+            out,
+            "        $m!({}, {:?}, {}{kind});",
+            attribute.function,
+            attribute.name,
+            type_name(&attribute.name)
+        );
+    }
+    let _ = writeln!(out, "    }};\n}}\n");
+
+    // `aria_attributes!(m)` calls `m!(function, "name")` or `m!(function, "name", Type)`
+    // for every ARIA attribute.
+    let _ = writeln!(out, "macro_rules! aria_attributes {{\n    ($m:ident) => {{");
+    for attribute in spec.attributes.iter().filter(|a| a.aria) {
+        let value_type = match &attribute.value_type {
+            Some(value_type) => format!(", {value_type}"),
+            None => String::new(),
+        };
+        let _ = writeln!(
+            out,
+            "        $m!({}, {:?}{value_type});",
+            attribute.function, attribute.name
+        );
+    }
+    let _ = writeln!(out, "    }};\n}}");
+
+    out
+}
+
+fn tag_macro_args(function: &str, tag: &str, void: bool) -> String {
+    let mut args = format!("{function}, {}", type_name(tag));
+    if function != tag {
+        let _ = write!(args, ", {tag:?}");
+    }
+    if void {
+        args.push_str(", leaf");
+    }
+    args
+}
+
+fn marker_types(spec: &Spec) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        "/// Marker types for HTML elements.\npub mod elements {{"
+    );
+    for element in &spec.elements {
+        let _ = writeln!(
+            out,
+            "    /// The `<{}>` element.\n    pub enum {} {{}}",
+            element.name,
+            type_name(&element.name)
+        );
+    }
+    let _ = writeln!(out, "}}\n");
+
+    let _ = writeln!(
+        out,
+        "/// Marker types for HTML attributes.\npub mod attributes {{"
+    );
+    for attribute in spec.attributes.iter().filter(|a| !a.aria) {
+        let _ = writeln!(
+            out,
+            "    /// The `{}` attribute.\n    pub enum {} {{}}",
+            attribute.name,
+            type_name(&attribute.name)
+        );
+    }
+    let _ = writeln!(out, "}}\n");
+
+    for element in &spec.elements {
+        let _ = writeln!(
+            out,
+            "impl ElementKind for elements::{} {{}}",
+            type_name(&element.name)
+        );
+    }
+    for attribute in spec.attributes.iter().filter(|a| !a.aria) {
+        let attribute_type = type_name(&attribute.name);
+        match &attribute.elements {
+            None => {
+                let _ = writeln!(
+                    out,
+                    "impl<E: ElementKind> Accepts<attributes::{attribute_type}> for E {{}}"
+                );
+            }
+            Some(elements) => {
+                let _ = writeln!(
+                    out,
+                    "impl Accepts<attributes::{attribute_type}> for AnyElement {{}}"
+                );
+                for element in elements {
+                    let _ = writeln!(
+                        out,
+                        "impl Accepts<attributes::{attribute_type}> for elements::{} {{}}",
+                        type_name(element)
+                    );
+                }
+            }
+        }
+    }
+
+    out
+}
+
+fn foreign_macros(html: &Spec, spec: &Spec, foreign: &Foreign) -> String {
+    let name = foreign.name;
+    let mut out = String::new();
+
+    // `svg_elements!(m)` calls `m!(function, Marker)` or `m!(function, Marker, "tag")`
+    // for every element, and `m!(function, html)` for elements shared with HTML.
+    let _ = writeln!(out, "macro_rules! {name}_elements {{\n    ($m:ident) => {{");
+    for element in &spec.elements {
+        let args = match element.html {
+            true => format!("{}, html", html_element(html, &element.name).function),
+            false => tag_macro_args(&element.function, &element.name, element.void),
+        };
+        let _ = writeln!(out, "        $m!({args});");
+    }
+    let _ = writeln!(out, "    }};\n}}\n");
+
+    // `svg_attributes!(m)` calls the macro like `html_attributes!`,
+    // and `m!(function, html)` for attributes shared with HTML.
+    let _ = writeln!(
+        out,
+        "macro_rules! {name}_attributes {{\n    ($m:ident) => {{"
+    );
+    for attribute in &spec.attributes {
+        if attribute.html {
+            let function = &html_attribute(html, &attribute.name).function;
+            let _ = writeln!(out, "        $m!({function}, html);");
+            continue;
+        }
+        let kind = match attribute.kind {
+            AttributeKind::Value => "",
+            AttributeKind::Bool => ", bool",
+            AttributeKind::Url => ", url",
+        };
+        let _ = writeln!(
+            out,
+            "        $m!({}, {:?}, {}{kind});",
+            attribute.function,
+            attribute.name,
+            type_name(&attribute.name)
+        );
+    }
+    let _ = writeln!(out, "    }};\n}}");
+
+    out
+}
+
+/// The marker types of a foreign table, included into `spec::svg` and similar modules.
+fn foreign_marker_types(html: &Spec, spec: &Spec, foreign: &Foreign) -> String {
+    let name = foreign.name;
+    let kind_trait = foreign.kind_trait;
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        "/// Marker types for {} elements.\npub mod elements {{",
+        foreign.title
+    );
+    for element in &spec.elements {
+        let marker = type_name(&element.name);
+        match element.html {
+            true => {
+                let _ = writeln!(out, "    pub use crate::spec::elements::{marker};");
+            }
+            false => {
+                let _ = writeln!(
+                    out,
+                    "    /// The `<{}>` element.\n    pub enum {marker} {{}}",
+                    element.name
+                );
+            }
+        }
+    }
+    let _ = writeln!(out, "}}\n");
+
+    let _ = writeln!(
+        out,
+        "/// Marker types for {} attributes.\npub mod attributes {{",
+        foreign.title
+    );
+    for attribute in spec.attributes.iter().filter(|a| !a.html) {
+        let _ = writeln!(
+            out,
+            "    /// The `{}` attribute.\n    pub enum {} {{}}",
+            attribute.name,
+            type_name(&attribute.name)
+        );
+    }
+    let _ = writeln!(out, "}}\n");
+
+    for element in &spec.elements {
+        let marker = type_name(&element.name);
+        if !element.html {
+            let _ = writeln!(out, "impl ElementKind for elements::{marker} {{}}");
+        }
+        let _ = writeln!(out, "impl {kind_trait} for elements::{marker} {{}}");
+    }
+
+    for attribute in &spec.attributes {
+        let marker = match attribute.html {
+            true => format!("crate::spec::attributes::{}", type_name(&attribute.name)),
+            false => format!("attributes::{}", type_name(&attribute.name)),
+        };
+        // HTML elements that already accept the attribute.
+        let html_elements: &[String] = match attribute.html {
+            true => match &html_attribute(html, &attribute.name).elements {
+                Some(elements) => elements,
+                // Global HTML attributes are accepted by all elements already.
+                None => continue,
+            },
+            false => &[],
+        };
+        let is_shared = |element: &str| spec.elements.iter().any(|e| e.name == element && e.html);
+
+        if !attribute.html {
+            let _ = writeln!(out, "impl Accepts<{marker}> for AnyElement {{}}");
+        }
+        match &attribute.elements {
+            None => {
+                if let Some(element) = html_elements.iter().find(|e| is_shared(e)) {
+                    panic!(
+                        "{name}.txt: attribute {:?} is global, but also applies to the HTML element {element:?}",
+                        attribute.name
+                    );
+                }
+                let _ = writeln!(out, "impl<E: {kind_trait}> Accepts<{marker}> for E {{}}");
+            }
+            Some(elements) => {
+                for element in elements {
+                    if is_shared(element) && html_elements.contains(element) {
+                        continue;
+                    }
+                    let _ = writeln!(
+                        out,
+                        "impl Accepts<{marker}> for elements::{} {{}}",
+                        type_name(element)
+                    );
+                }
+            }
+        }
+    }
+
+    out
+}
+
+fn html_element<'a>(html: &'a Spec, name: &str) -> &'a parse::Element {
+    html.elements
+        .iter()
+        .find(|element| element.name == name)
+        .unwrap_or_else(|| panic!("element {name:?} is not in html.txt"))
+}
+
+fn html_attribute<'a>(html: &'a Spec, name: &str) -> &'a parse::Attribute {
+    html.attributes
+        .iter()
+        .find(|attribute| attribute.name == name)
+        .unwrap_or_else(|| panic!("attribute {name:?} is not in html.txt"))
+}
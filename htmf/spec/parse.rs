@@ -0,0 +1,175 @@
+//! Parser for the tables in `spec/`, shared by the build scripts of `htmf` and `askama_to_htmf`.
+
+#![allow(dead_code)]
+
+pub struct Element {
+    pub name: String,
+    pub void: bool,
+    /// Whether the element is defined by `html.txt`, and only listed to apply attributes to it.
+    pub html: bool,
+    /// The name of the tag function.
+    pub function: String,
+    /// The name of the builder method.
+    pub method: String,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum AttributeKind {
+    Value,
+    Bool,
+    Url,
+}
+
+pub struct Attribute {
+    pub name: String,
+    pub kind: AttributeKind,
+    /// Whether the attribute is defined by `html.txt`, and only listed to apply it to more elements.
+    pub html: bool,
+    /// The name of the attribute function.
+    pub function: String,
+    /// The elements this attribute applies to, or `None` for global attributes.
+    pub elements: Option<Vec<String>>,
+    /// Whether the attribute is an ARIA state or property, whose function is defined in `htmf::aria`.
+    pub aria: bool,
+    /// The type the values of an ARIA attribute are converted into, like `Tristate` or `i32`.
+    pub value_type: Option<String>,
+}
+
+pub struct Spec {
+    pub elements: Vec<Element>,
+    pub attributes: Vec<Attribute>,
+}
+
+/// The name of the marker type for an element or attribute, like `HttpEquiv` for `http-equiv`.
+pub fn type_name(name: &str) -> String {
+    name.split('-')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+/// The default name of the function for an element or attribute,
+/// like `http_equiv` for `http-equiv` or `view_box` for `viewBox`.
+fn function_name(name: &str) -> String {
+    let mut function = String::new();
+    for c in name.chars() {
+        match c {
+            '-' => function.push('_'),
+            c if c.is_ascii_uppercase() => {
+                function.push('_');
+                function.push(c.to_ascii_lowercase());
+            }
+            c => function.push(c),
+        }
+    }
+    function
+}
+
+/// Parse the table in `source`, named `file` in error messages.
+///
+/// # Panics
+///
+/// If the table is malformed, or an attribute applies to an element that is not in the table.
+pub fn parse(file: &str, source: &str) -> Spec {
+    let mut spec = Spec {
+        elements: Vec::new(),
+        attributes: Vec::new(),
+    };
+
+    for (number, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fail = |message: &str| -> ! { panic!("{file}:{}: {message}", number + 1) };
+
+        let mut words = line.split_ascii_whitespace();
+        let keyword = words.next().unwrap_or_default();
+        let Some(name) = words.next() else {
+            fail("missing name");
+        };
+        let default_function = function_name(name);
+
+        match keyword {
+            "element" => {
+                let mut element = Element {
+                    name: name.to_string(),
+                    void: false,
+                    html: false,
+                    function: default_function,
+                    method: String::new(),
+                };
+                for word in words {
+                    match word.split_once('=') {
+                        None if word == "void" => element.void = true,
+                        None if word == "html" => element.html = true,
+                        Some(("fn", function)) => element.function = function.to_string(),
+                        Some(("method", method)) => element.method = method.to_string(),
+                        _ => fail(&format!("unknown element option {word:?}")),
+                    }
+                }
+                if element.method.is_empty() {
+                    element.method = element.function.clone();
+                }
+                spec.elements.push(element);
+            }
+            "attribute" => {
+                let mut attribute = Attribute {
+                    name: name.to_string(),
+                    kind: AttributeKind::Value,
+                    html: false,
+                    function: default_function,
+                    elements: None,
+                    aria: false,
+                    value_type: None,
+                };
+                for word in words {
+                    match word.split_once('=') {
+                        None if word == "bool" => attribute.kind = AttributeKind::Bool,
+                        None if word == "url" => attribute.kind = AttributeKind::Url,
+                        None if word == "html" => attribute.html = true,
+                        None if word == "aria" => attribute.aria = true,
+                        Some(("type", value_type)) => {
+                            attribute.value_type = Some(value_type.to_string())
+                        }
+                        Some(("fn", function)) => attribute.function = function.to_string(),
+                        Some(("on", elements)) => {
+                            attribute.elements =
+                                Some(elements.split(',').map(str::to_string).collect())
+                        }
+                        _ => fail(&format!("unknown attribute option {word:?}")),
+                    }
+                }
+                if attribute.value_type.is_some() && !attribute.aria {
+                    fail("only `aria` attributes can have a `type`");
+                }
+                if attribute.aria
+                    && (attribute.kind != AttributeKind::Value || attribute.elements.is_some())
+                {
+                    fail("`aria` attributes are global and can't be `bool` or `url`");
+                }
+                spec.attributes.push(attribute);
+            }
+            _ => fail(&format!("unknown keyword {keyword:?}")),
+        }
+    }
+
+    for attribute in &spec.attributes {
+        for element in attribute.elements.iter().flatten() {
+            if !spec.elements.iter().any(|e| e.name == *element) {
+                panic!(
+                    "{file}: attribute {:?} applies to unknown element {element:?}",
+                    attribute.name
+                );
+            }
+        }
+    }
+
+    spec
+}
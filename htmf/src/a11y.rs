@@ -0,0 +1,420 @@
+//! Checks element trees for common accessibility failures.
+//!
+//! [`Element::check_a11y`] reports
+//!
+//! - `<img>` elements without an `alt` attribute,
+//! - form controls without a label, either a `<label>` around them, a `<label>` whose `for`
+//!   attribute matches their `id`, or an `aria-label` or `aria-labelledby` attribute,
+//! - ids that are used more than once,
+//! - buttons without an accessible name,
+//! - headings that skip a level, like an `<h4>` following an `<h2>`,
+//! - and `aria-labelledby` attributes that refer to ids that don't exist.
+//!
+//! ```rust
+//! use htmf::prelude::*;
+//!
+//! let form = form([]).with((
+//!     label(for_("username")).with("Username"),
+//!     input([type_("text"), id("username")]),
+//!     input(type_("password")),
+//!     button(type_("submit")).with(img(src("/arrow.svg"))),
+//! ));
+//! let issues: Vec<String> = form.check_a11y().iter().map(ToString::to_string).collect();
+//! assert_eq!(
+//!     issues,
+//!     [
+//!         "form > input[2]: `input` has no label",
+//!         "form > button[3]: `button` has no accessible name",
+//!         "form > button[3] > img[0]: `img` has no `alt` attribute",
+//!     ]
+//! );
+//! ```
+//!
+//! Only the element itself is checked, so ids and labels that are outside of it,
+//! like in the layout around a partial, count as missing. The contents of `<template>`
+//! elements are not checked. Raw HTML is assumed to give buttons a name, but its ids
+//! and labels are not known.
+
+use std::{borrow::Cow, collections::HashSet};
+
+use crate::{
+    attr::{AttrValue, Attrs},
+    element::Element,
+    validate::NodePath,
+};
+
+/// An accessibility failure found by [`Element::check_a11y`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Issue {
+    /// Where the offending element is.
+    pub path: NodePath,
+    pub problem: Problem,
+}
+
+/// What is wrong with an element.
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum Problem {
+    /// An `<img>` without `alt` text. Use an empty `alt` for decorative images.
+    MissingAlt,
+    /// A form control like `<input>`, `<select>` or `<textarea>` without a label.
+    MissingLabel { tag: Cow<'static, str> },
+    /// A button without text, `aria-label` or `aria-labelledby`.
+    /// `tag` is `input` for `<input type="button">` and `<input type="image">`.
+    MissingButtonName { tag: Cow<'static, str> },
+    /// An id that an earlier element already uses.
+    DuplicateId { id: String },
+    /// A heading of `level` that follows a heading of `previous`,
+    /// skipping the levels in between.
+    SkippedHeadingLevel { previous: u8, level: u8 },
+    /// An `aria-labelledby` attribute that refers to `id`, which no element uses.
+    MissingLabelledbyTarget { id: String },
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::MissingAlt => f.write_str("`img` has no `alt` attribute"),
+            Problem::MissingLabel { tag } => write!(f, "`{tag}` has no label"),
+            Problem::MissingButtonName { tag } => write!(f, "`{tag}` has no accessible name"),
+            Problem::DuplicateId { id } => write!(f, "the id `{id}` is already used"),
+            Problem::SkippedHeadingLevel { previous, level } => {
+                write!(
+                    f,
+                    "`h{level}` follows `h{previous}`, skipping `h{}`",
+                    previous + 1
+                )
+            }
+            Problem::MissingLabelledbyTarget { id } => {
+                write!(f, "`aria-labelledby` refers to the missing id `{id}`")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.problem)
+    }
+}
+
+impl Element {
+    /// Check this element and its descendants for accessibility failures.
+    /// See the [module docs](crate::a11y) for details.
+    pub fn check_a11y(&self) -> Vec<Issue> {
+        let mut references = References::default();
+        references.collect(self);
+        let mut checker = Checker {
+            references,
+            path: NodePath::default(),
+            seen_ids: HashSet::new(),
+            heading_level: None,
+            issues: Vec::new(),
+        };
+        checker.visit(self, 0, false);
+        checker.issues
+    }
+}
+
+/// The non-empty, trimmed value of the attribute called `name`.
+fn value<'a>(attrs: &'a Attrs, name: &str) -> Option<&'a str> {
+    match attrs.get(name) {
+        Some(AttrValue::String(value)) if !value.trim().is_empty() => Some(value.trim()),
+        _ => None,
+    }
+}
+
+/// Whether a control or button is named by `aria-label` or `aria-labelledby`.
+fn has_aria_name(attrs: &Attrs) -> bool {
+    value(attrs, "aria-label").is_some() || value(attrs, "aria-labelledby").is_some()
+}
+
+/// Whether `element` contains anything that names a button containing it.
+fn has_text(element: &Element) -> bool {
+    match element {
+        Element::Text { text } | Element::CData { text } => !text.trim().is_empty(),
+        Element::Raw { html } => !html.trim().is_empty(),
+        Element::LeafTag { tag, attrs } => tag == "img" && value(attrs, "alt").is_some(),
+        Element::Tag { attrs, .. } if value(attrs, "aria-hidden") == Some("true") => false,
+        Element::Tag { attrs, .. } if value(attrs, "aria-label").is_some() => true,
+        Element::Tag { children, .. }
+        | Element::Fragment { children }
+        | Element::Document { children } => children.iter().any(has_text),
+        Element::ProcessingInstruction { .. } | Element::Nothing => false,
+    }
+}
+
+fn heading_level(tag: &str) -> Option<u8> {
+    match tag.as_bytes() {
+        [b'h', level @ b'1'..=b'6'] => Some(level - b'0'),
+        _ => None,
+    }
+}
+
+/// The ids and `label` targets in a tree, collected before checking it.
+#[derive(Default)]
+struct References<'a> {
+    ids: HashSet<&'a str>,
+    label_targets: HashSet<&'a str>,
+}
+
+impl<'a> References<'a> {
+    fn collect(&mut self, element: &'a Element) {
+        match element {
+            Element::Tag { tag, attrs, .. } | Element::LeafTag { tag, attrs } => {
+                if let Some(id) = value(attrs, "id") {
+                    self.ids.insert(id);
+                }
+                if tag == "label" {
+                    self.label_targets.extend(value(attrs, "for"));
+                }
+                if let Element::Tag { children, .. } = element {
+                    if tag != "template" {
+                        children.iter().for_each(|child| self.collect(child));
+                    }
+                }
+            }
+            Element::Fragment { children } | Element::Document { children } => {
+                children.iter().for_each(|child| self.collect(child));
+            }
+            _ => {}
+        }
+    }
+}
+
+struct Checker<'a> {
+    references: References<'a>,
+    path: NodePath,
+    seen_ids: HashSet<&'a str>,
+    /// The level of the last heading, in document order.
+    heading_level: Option<u8>,
+    issues: Vec<Issue>,
+}
+
+impl<'a> Checker<'a> {
+    fn visit(&mut self, element: &'a Element, index: usize, in_label: bool) {
+        match element {
+            Element::Tag { tag, attrs, .. } | Element::LeafTag { tag, attrs } => {
+                self.path.push(index, tag.clone());
+                self.check_element(element, tag, attrs, in_label);
+                if let Element::Tag { children, .. } = element {
+                    if tag != "template" {
+                        let in_label = in_label || tag == "label";
+                        for (i, child) in children.iter().enumerate() {
+                            self.visit(child, i, in_label);
+                        }
+                    }
+                }
+                self.path.pop();
+            }
+            Element::Fragment { children } | Element::Document { children } => {
+                let name = match element {
+                    Element::Fragment { .. } => "#fragment",
+                    _ => "#document",
+                };
+                self.path.push(index, Cow::Borrowed(name));
+                for (i, child) in children.iter().enumerate() {
+                    self.visit(child, i, in_label);
+                }
+                self.path.pop();
+            }
+            _ => {}
+        }
+    }
+
+    fn check_element(&mut self, element: &Element, tag: &str, attrs: &'a Attrs, in_label: bool) {
+        let input_type = value(attrs, "type").map(str::to_ascii_lowercase);
+        match (tag, input_type.as_deref()) {
+            ("img", _) if attrs.get("alt").is_none() => self.report(Problem::MissingAlt),
+            ("input", Some("hidden" | "submit" | "reset")) => {}
+            ("input", Some("button")) if value(attrs, "value").is_none() => {
+                self.report_unnamed_button(tag, attrs)
+            }
+            ("input", Some("image")) if value(attrs, "alt").is_none() => {
+                self.report_unnamed_button(tag, attrs)
+            }
+            ("input", Some("button" | "image")) => {}
+            ("input" | "select" | "textarea", _) => {
+                let labelled_by_for =
+                    value(attrs, "id").is_some_and(|id| self.references.label_targets.contains(id));
+                if !in_label && !labelled_by_for && !has_aria_name(attrs) {
+                    self.report(Problem::MissingLabel {
+                        tag: Cow::Owned(tag.to_string()),
+                    });
+                }
+            }
+            ("button", _) if !has_text(element) => self.report_unnamed_button(tag, attrs),
+            _ => {}
+        }
+
+        if let Some(level) = heading_level(tag) {
+            if let Some(previous) = self.heading_level {
+                if level > previous + 1 {
+                    self.report(Problem::SkippedHeadingLevel { previous, level });
+                }
+            }
+            self.heading_level = Some(level);
+        }
+
+        if let Some(id) = value(attrs, "id") {
+            if !self.seen_ids.insert(id) {
+                self.report(Problem::DuplicateId { id: id.to_string() });
+            }
+        }
+
+        if let Some(ids) = value(attrs, "aria-labelledby") {
+            for id in ids.split_ascii_whitespace() {
+                if !self.references.ids.contains(id) {
+                    self.report(Problem::MissingLabelledbyTarget { id: id.to_string() });
+                }
+            }
+        }
+    }
+
+    fn report_unnamed_button(&mut self, tag: &str, attrs: &Attrs) {
+        if !has_aria_name(attrs) {
+            self.report(Problem::MissingButtonName {
+                tag: Cow::Owned(tag.to_string()),
+            });
+        }
+    }
+
+    fn report(&mut self, problem: Problem) {
+        self.issues.push(Issue {
+            path: self.path.clone(),
+            problem,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::prelude::*;
+
+    fn issues(element: Element) -> Vec<String> {
+        element
+            .check_a11y()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn login_form(username_label: Element, password_label: Element) -> Element {
+        form([action("login"), method("post")]).with((
+            h1([]).with("Sign in to your account"),
+            username_label,
+            input([
+                type_("text"),
+                id("username"),
+                name("credentials[username]"),
+                required(true),
+            ]),
+            password_label,
+            input([
+                type_("password"),
+                id("password"),
+                name("credentials[password]"),
+                required(true),
+            ]),
+            button(type_("submit")).with((text("Sign in"), span(class("htmx-indicator")))),
+        ))
+    }
+
+    #[test]
+    fn login_page() {
+        let form = login_form(
+            label(for_("username")).with("Username"),
+            label(for_("password")).with("Password"),
+        );
+        assert_eq!(issues(form), Vec::<String>::new());
+
+        let form = login_form(
+            label(attr("name", "credentials[username]")).with("Username"),
+            label(for_("pasword")).with("Password"),
+        );
+        assert_eq!(
+            issues(form),
+            [
+                "form > input[2]: `input` has no label",
+                "form > input[4]: `input` has no label",
+            ]
+        );
+    }
+
+    #[test]
+    fn labels() {
+        let element = div([]).with((
+            label([]).with((text("Name"), span([]).with(input(name("name"))))),
+            select(aria_label("Country")),
+            p(id("bio-label")).with("Bio"),
+            textarea(aria_labelledby("bio-label")),
+            input([type_("hidden"), name("token")]),
+            input([type_("submit"), value("Save")]),
+            input(type_("checkbox")),
+        ));
+        assert_eq!(issues(element), ["div > input[6]: `input` has no label"]);
+    }
+
+    #[test]
+    fn images_and_buttons() {
+        let element = div([]).with((
+            img(src("/logo.png")),
+            img([src("/divider.png"), alt("")]),
+            button([]).with(img([src("/close.svg"), alt("Close")])),
+            button(aria_label("Menu")).with(span(class("icon"))),
+            button([]).with(span(aria_hidden(true)).with("×")),
+            button([]).with("  "),
+            input([type_("button"), value("Go")]),
+            input(type_("button")),
+            input([type_("image"), src("/go.png")]),
+        ));
+        assert_eq!(
+            issues(element),
+            [
+                "div > img[0]: `img` has no `alt` attribute",
+                "div > button[4]: `button` has no accessible name",
+                "div > button[5]: `button` has no accessible name",
+                "div > input[7]: `input` has no accessible name",
+                "div > input[8]: `input` has no accessible name",
+            ]
+        );
+    }
+
+    #[test]
+    fn ids() {
+        let element = div([]).with((
+            h2(id("title")).with("Title"),
+            p(id("title")),
+            section(aria_labelledby(["title", "subtitle"])),
+            template([]).with(p(id("title"))),
+        ));
+        assert_eq!(
+            issues(element),
+            [
+                "div > p[1]: the id `title` is already used",
+                "div > section[2]: `aria-labelledby` refers to the missing id `subtitle`",
+            ]
+        );
+    }
+
+    #[test]
+    fn heading_levels() {
+        let element = document().with(html([]).with(body([]).with((
+            h2([]).with("Section"),
+            h3([]).with("Subsection"),
+            section([]).with(h5([]).with("Skipped")),
+            h2([]).with("Section"),
+            h1([]).with("Back to the top"),
+            h4([]).with("Skipped"),
+        ))));
+        assert_eq!(
+            issues(element),
+            [
+                "#document > html[0] > body[0] > section[2] > h5[0]: `h5` follows `h3`, skipping `h4`",
+                "#document > html[0] > body[0] > h4[5]: `h4` follows `h1`, skipping `h2`",
+            ]
+        );
+    }
+}
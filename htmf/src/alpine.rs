@@ -0,0 +1,359 @@
+//! Directives for [Alpine.js](https://alpinejs.dev).
+//!
+//! Directive names are written in their long form, like `x-on:click` instead of `@click`,
+//! so they are valid attribute names in any output syntax.
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use htmf::alpine::*;
+//! use htmf::prelude::*;
+//!
+//! let state = serde_json::json!({ "open": false, "query": "" });
+//! let dropdown = div(x_data(&state)).with((
+//!     button(x_on("click", "open = !open")).with("Toggle"),
+//!     input([
+//!         x_model_with(Model::new().debounce(Duration::from_millis(300)), "query"),
+//!         x_on(On::event("keydown").key("escape").prevent(), "open = false"),
+//!     ]),
+//!     ul([x_show("open"), x_bind("class", "{ 'shadow': open }")]),
+//! ));
+//! assert_eq!(
+//!     dropdown.to_html(),
+//!     r#"<div x-data="{&quot;open&quot;:false,&quot;query&quot;:&quot;&quot;}"><button x-on:click="open = !open">Toggle</button><input x-model.debounce.300ms="query" x-on:keydown.escape.prevent="open = false"/><ul x-show="open" x-bind:class="{ &#x27;shadow&#x27;: open }"></ul></div>"#
+//! );
+//! ```
+
+use std::{borrow::Cow, fmt::Write, time::Duration};
+
+use serde::Serialize;
+
+use crate::{
+    attr::{self, Attr, AttrValue, Attrs, IntoAttrValue},
+    json,
+};
+
+macro_rules! define_directive_function {
+    ($name:ident, $key:literal, flag) => {
+        pub fn $name<E>() -> Attrs<E> {
+            Attr(Cow::Borrowed($key), AttrValue::Present).into()
+        }
+    };
+    ($name:ident, $key:literal) => {
+        pub fn $name<E, C, M>(value: C) -> Attrs<E>
+        where
+            C: IntoAttrValue<M>,
+        {
+            Attr(Cow::Borrowed($key), value.into_attr_value()).into()
+        }
+    };
+}
+
+define_directive_function!(x_cloak, "x-cloak", flag);
+define_directive_function!(x_effect, "x-effect");
+define_directive_function!(x_html, "x-html");
+define_directive_function!(x_id, "x-id");
+define_directive_function!(x_if, "x-if");
+define_directive_function!(x_ignore, "x-ignore", flag);
+define_directive_function!(x_init, "x-init");
+define_directive_function!(x_key, "x-key");
+define_directive_function!(x_model, "x-model");
+define_directive_function!(x_ref, "x-ref");
+define_directive_function!(x_show, "x-show");
+define_directive_function!(x_teleport, "x-teleport");
+define_directive_function!(x_text, "x-text");
+define_directive_function!(x_transition, "x-transition", flag);
+
+/// `x-data`, serialized as JSON, which is also a valid JavaScript object.
+///
+/// # Panics
+///
+/// If `value` can't be serialized as JSON. Use [`try_x_data`] to handle the error instead.
+pub fn x_data<E, T>(value: &T) -> Attrs<E>
+where
+    T: Serialize + ?Sized,
+{
+    json::json_attr(Cow::Borrowed("x-data"), value)
+}
+
+/// `x-data`, serialized as JSON, or the error if `value` can't be serialized.
+pub fn try_x_data<E, T>(value: &T) -> Result<Attrs<E>, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    json::try_json_attr(Cow::Borrowed("x-data"), value)
+}
+
+/// `x-bind:name`, which sets the attribute `name` to the result of `expression`.
+///
+/// # Panics
+///
+/// If `name` is not a valid attribute name.
+pub fn x_bind<E, C, M>(name: &str, expression: C) -> Attrs<E>
+where
+    C: IntoAttrValue<M>,
+{
+    directive(format!("x-bind:{name}"), expression)
+}
+
+/// `x-on:event`, which runs `handler` when the event is dispatched.
+///
+/// # Panics
+///
+/// If the event name or its modifiers don't form a valid attribute name.
+pub fn x_on<E, O, C, M>(event: O, handler: C) -> Attrs<E>
+where
+    O: Into<On>,
+    C: IntoAttrValue<M>,
+{
+    let on = event.into();
+    let mut name = format!("x-on:{}", on.event);
+    for modifier in &on.modifiers {
+        let _ = write!(name, ".{modifier}");
+    }
+    directive(name, handler)
+}
+
+/// `x-model` with modifiers.
+pub fn x_model_with<E, C, M>(model: Model, expression: C) -> Attrs<E>
+where
+    C: IntoAttrValue<M>,
+{
+    let mut name = String::from("x-model");
+    for modifier in &model.modifiers {
+        let _ = write!(name, ".{modifier}");
+    }
+    directive(name, expression)
+}
+
+/// `x-for="item in items"`. Alpine only allows this on `<template>` elements:
+///
+/// ```rust
+/// use htmf::alpine::*;
+/// use htmf::prelude::*;
+///
+/// let list = ul([]).with(
+///     template([x_for("todo", "todos"), x_key("todo.id")]).with(li(x_text("todo.title"))),
+/// );
+/// assert_eq!(
+///     list.to_html(),
+///     r#"<ul><template x-for="todo in todos" x-key="todo.id"><li x-text="todo.title"></li></template></ul>"#
+/// );
+/// ```
+pub fn x_for<E>(item: &str, items: &str) -> Attrs<E> {
+    Attr(
+        Cow::Borrowed("x-for"),
+        AttrValue::String(format!("{item} in {items}")),
+    )
+    .into()
+}
+
+/// `x-for="(item, index) in items"`.
+pub fn x_for_indexed<E>(item: &str, index: &str, items: &str) -> Attrs<E> {
+    Attr(
+        Cow::Borrowed("x-for"),
+        AttrValue::String(format!("({item}, {index}) in {items}")),
+    )
+    .into()
+}
+
+fn directive<E, C, M>(name: String, value: C) -> Attrs<E>
+where
+    C: IntoAttrValue<M>,
+{
+    let name = attr::checked_name(Cow::Owned(name));
+    Attr(name, value.into_attr_value()).into()
+}
+
+/// Write `duration` as an Alpine modifier argument, like `500ms`.
+fn duration_modifier(duration: Duration) -> String {
+    format!("{}ms", duration.as_millis())
+}
+
+/// An event with modifiers, used by [`x_on`].
+///
+/// Strings convert into an event without modifiers.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct On {
+    event: String,
+    modifiers: Vec<String>,
+}
+
+impl On {
+    pub fn event<N: Into<String>>(name: N) -> On {
+        On {
+            event: name.into(),
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Call `preventDefault()` on the event.
+    pub fn prevent(self) -> On {
+        self.modifier("prevent")
+    }
+
+    /// Call `stopPropagation()` on the event.
+    pub fn stop(self) -> On {
+        self.modifier("stop")
+    }
+
+    /// Only handle events dispatched outside of the element.
+    pub fn outside(self) -> On {
+        self.modifier("outside")
+    }
+
+    /// Listen on the window instead of the element.
+    pub fn window(self) -> On {
+        self.modifier("window")
+    }
+
+    /// Listen on the document instead of the element.
+    pub fn document(self) -> On {
+        self.modifier("document")
+    }
+
+    pub fn once(self) -> On {
+        self.modifier("once")
+    }
+
+    /// Only handle events dispatched by the element itself, not its children.
+    pub fn self_(self) -> On {
+        self.modifier("self")
+    }
+
+    /// Convert the kebab-case event name to camelCase.
+    pub fn camel(self) -> On {
+        self.modifier("camel")
+    }
+
+    /// Convert dashes in the event name to dots.
+    pub fn dot(self) -> On {
+        self.modifier("dot")
+    }
+
+    pub fn passive(self) -> On {
+        self.modifier("passive")
+    }
+
+    pub fn capture(self) -> On {
+        self.modifier("capture")
+    }
+
+    /// Wait for `delay` without further events before handling the last one.
+    pub fn debounce(self, delay: Duration) -> On {
+        self.modifier("debounce").modifier(duration_modifier(delay))
+    }
+
+    /// Handle at most one event per `interval`.
+    pub fn throttle(self, interval: Duration) -> On {
+        self.modifier("throttle")
+            .modifier(duration_modifier(interval))
+    }
+
+    /// Only handle keyboard events for `key`, in kebab-case, like `enter` or `page-down`.
+    /// Call this multiple times for key combinations like `shift.enter`.
+    pub fn key(self, key: &str) -> On {
+        self.modifier(key)
+    }
+
+    fn modifier<S: Into<String>>(mut self, modifier: S) -> On {
+        self.modifiers.push(modifier.into());
+        self
+    }
+}
+
+impl From<&str> for On {
+    fn from(event: &str) -> Self {
+        On::event(event)
+    }
+}
+
+impl From<String> for On {
+    fn from(event: String) -> Self {
+        On::event(event)
+    }
+}
+
+/// Modifiers for [`x_model_with`].
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Model {
+    modifiers: Vec<String>,
+}
+
+impl Model {
+    pub fn new() -> Model {
+        Model::default()
+    }
+
+    /// Only update the data when the input loses focus.
+    pub fn lazy(self) -> Model {
+        self.modifier("lazy")
+    }
+
+    /// Store the input as a number.
+    pub fn number(self) -> Model {
+        self.modifier("number")
+    }
+
+    /// Store the input as a boolean.
+    pub fn boolean(self) -> Model {
+        self.modifier("boolean")
+    }
+
+    /// Initialize the data from the input's `value` attribute.
+    pub fn fill(self) -> Model {
+        self.modifier("fill")
+    }
+
+    /// Wait for `delay` without further input before updating the data.
+    pub fn debounce(self, delay: Duration) -> Model {
+        self.modifier("debounce").modifier(duration_modifier(delay))
+    }
+
+    /// Update the data at most once per `interval`.
+    pub fn throttle(self, interval: Duration) -> Model {
+        self.modifier("throttle")
+            .modifier(duration_modifier(interval))
+    }
+
+    fn modifier<S: Into<String>>(mut self, modifier: S) -> Model {
+        self.modifiers.push(modifier.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn directives() {
+        let element = div([
+            x_on(
+                On::event("click")
+                    .outside()
+                    .throttle(Duration::from_secs(1)),
+                "close()",
+            ),
+            x_on(On::event("keyup").key("shift").key("enter"), "submit()"),
+            x_model_with(Model::new().lazy().number(), "count"),
+            x_cloak(),
+            x_text("\"</div>\""),
+        ]);
+        assert_eq!(
+            element.to_html(),
+            r#"<div x-on:click.outside.throttle.1000ms="close()" x-on:keyup.shift.enter="submit()" x-model.lazy.number="count" x-cloak x-text="&quot;&lt;/div&gt;&quot;"></div>"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid attribute name")]
+    fn invalid_event_name() {
+        let _: Attrs = x_on("click onload=alert(1)", "");
+    }
+}
@@ -0,0 +1,384 @@
+//! Typed [WAI-ARIA 1.2](https://www.w3.org/TR/wai-aria-1.2/) states, properties and roles.
+//!
+//! Everything in this module is also exported by the [`declare`](crate::declare) and
+//! [`declare_inline`](crate::declare_inline) modules.
+//!
+//! ```rust
+//! use htmf::prelude::*;
+//!
+//! let toggle = button([
+//!     role(Role::Switch),
+//!     aria_checked(Tristate::Mixed),
+//!     aria_controls(["panel-1", "panel-2"]),
+//!     aria_level(2),
+//! ]);
+//! assert_eq!(
+//!     toggle.to_html(),
+//!     r#"<button role="switch" aria-checked="mixed" aria-controls="panel-1 panel-2" aria-level="2"></button>"#
+//! );
+//! ```
+
+use std::{borrow::Cow, fmt};
+
+use crate::attr::{Attr, AttrValue, Attrs, IntoAttrValue};
+
+macro_rules! define_aria_function {
+    ($name:ident, $key:literal, $type:ty) => {
+        pub fn $name<E, V>(value: V) -> Attrs<E>
+        where
+            V: Into<$type>,
+        {
+            let value: $type = value.into();
+            Attr(Cow::Borrowed($key), AttrValue::String(value.to_string())).into()
+        }
+    };
+    ($name:ident, $key:literal) => {
+        pub fn $name<E, C, M>(value: C) -> Attrs<E>
+        where
+            C: IntoAttrValue<M>,
+        {
+            Attr(Cow::Borrowed($key), value.into_attr_value()).into()
+        }
+    };
+}
+
+macro_rules! define_token_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $token:literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+        pub enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $token),*
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+aria_attributes!(define_aria_function);
+
+define_token_enum!(
+    /// Values of `aria-checked` and `aria-pressed`.
+    Tristate {
+        True => "true",
+        False => "false",
+        Mixed => "mixed",
+    }
+);
+
+impl From<bool> for Tristate {
+    fn from(value: bool) -> Self {
+        match value {
+            true => Tristate::True,
+            false => Tristate::False,
+        }
+    }
+}
+
+define_token_enum!(
+    /// Values of `aria-current`.
+    Current {
+        Page => "page",
+        Step => "step",
+        Location => "location",
+        Date => "date",
+        Time => "time",
+        True => "true",
+        False => "false",
+    }
+);
+
+impl From<bool> for Current {
+    fn from(value: bool) -> Self {
+        match value {
+            true => Current::True,
+            false => Current::False,
+        }
+    }
+}
+
+define_token_enum!(
+    /// Values of `aria-invalid`.
+    Invalid {
+        True => "true",
+        False => "false",
+        Grammar => "grammar",
+        Spelling => "spelling",
+    }
+);
+
+impl From<bool> for Invalid {
+    fn from(value: bool) -> Self {
+        match value {
+            true => Invalid::True,
+            false => Invalid::False,
+        }
+    }
+}
+
+define_token_enum!(
+    /// Values of `aria-haspopup`. `true` means the same as [`HasPopup::Menu`].
+    HasPopup {
+        True => "true",
+        False => "false",
+        Menu => "menu",
+        Listbox => "listbox",
+        Tree => "tree",
+        Grid => "grid",
+        Dialog => "dialog",
+    }
+);
+
+impl From<bool> for HasPopup {
+    fn from(value: bool) -> Self {
+        match value {
+            true => HasPopup::True,
+            false => HasPopup::False,
+        }
+    }
+}
+
+define_token_enum!(
+    /// Values of `aria-autocomplete`.
+    Autocomplete {
+        Inline => "inline",
+        List => "list",
+        Both => "both",
+        None => "none",
+    }
+);
+
+define_token_enum!(
+    /// Values of `aria-live`.
+    Live {
+        Assertive => "assertive",
+        Polite => "polite",
+        Off => "off",
+    }
+);
+
+define_token_enum!(
+    /// Values of `aria-orientation`.
+    Orientation {
+        Horizontal => "horizontal",
+        Vertical => "vertical",
+    }
+);
+
+define_token_enum!(
+    /// Values of `aria-sort`.
+    Sort {
+        Ascending => "ascending",
+        Descending => "descending",
+        None => "none",
+        Other => "other",
+    }
+);
+
+define_token_enum!(
+    /// Tokens of `aria-relevant`.
+    Relevant {
+        Additions => "additions",
+        Removals => "removals",
+        Text => "text",
+        All => "all",
+    }
+);
+
+define_token_enum!(
+    /// Non-abstract roles for [`role`].
+    Role {
+        Alert => "alert",
+        AlertDialog => "alertdialog",
+        Application => "application",
+        Article => "article",
+        Banner => "banner",
+        Blockquote => "blockquote",
+        Button => "button",
+        Caption => "caption",
+        Cell => "cell",
+        Checkbox => "checkbox",
+        Code => "code",
+        ColumnHeader => "columnheader",
+        Combobox => "combobox",
+        Complementary => "complementary",
+        ContentInfo => "contentinfo",
+        Definition => "definition",
+        Deletion => "deletion",
+        Dialog => "dialog",
+        Document => "document",
+        Emphasis => "emphasis",
+        Feed => "feed",
+        Figure => "figure",
+        Form => "form",
+        Generic => "generic",
+        Grid => "grid",
+        GridCell => "gridcell",
+        Group => "group",
+        Heading => "heading",
+        Img => "img",
+        Insertion => "insertion",
+        Link => "link",
+        List => "list",
+        Listbox => "listbox",
+        ListItem => "listitem",
+        Log => "log",
+        Main => "main",
+        Marquee => "marquee",
+        Math => "math",
+        Menu => "menu",
+        Menubar => "menubar",
+        MenuItem => "menuitem",
+        MenuItemCheckbox => "menuitemcheckbox",
+        MenuItemRadio => "menuitemradio",
+        Meter => "meter",
+        Navigation => "navigation",
+        None => "none",
+        Note => "note",
+        Option => "option",
+        Paragraph => "paragraph",
+        Presentation => "presentation",
+        ProgressBar => "progressbar",
+        Radio => "radio",
+        RadioGroup => "radiogroup",
+        Region => "region",
+        Row => "row",
+        RowGroup => "rowgroup",
+        RowHeader => "rowheader",
+        Scrollbar => "scrollbar",
+        Search => "search",
+        Searchbox => "searchbox",
+        Separator => "separator",
+        Slider => "slider",
+        SpinButton => "spinbutton",
+        Status => "status",
+        Strong => "strong",
+        Subscript => "subscript",
+        Superscript => "superscript",
+        Switch => "switch",
+        Tab => "tab",
+        Table => "table",
+        TabList => "tablist",
+        TabPanel => "tabpanel",
+        Term => "term",
+        Textbox => "textbox",
+        Time => "time",
+        Timer => "timer",
+        Toolbar => "toolbar",
+        Tooltip => "tooltip",
+        Tree => "tree",
+        TreeGrid => "treegrid",
+        TreeItem => "treeitem",
+    }
+);
+
+/// A space-separated list of [`Relevant`] tokens, used by [`aria_relevant`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct RelevantChanges(Vec<Relevant>);
+
+impl From<Relevant> for RelevantChanges {
+    fn from(value: Relevant) -> Self {
+        RelevantChanges(vec![value])
+    }
+}
+
+impl<const N: usize> From<[Relevant; N]> for RelevantChanges {
+    fn from(value: [Relevant; N]) -> Self {
+        RelevantChanges(value.into())
+    }
+}
+
+impl fmt::Display for RelevantChanges {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, relevant) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(relevant.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// A space-separated list of element IDs, used by attributes like [`aria_labelledby`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct IdRefs(Vec<String>);
+
+impl From<&str> for IdRefs {
+    fn from(id: &str) -> Self {
+        IdRefs(vec![id.to_string()])
+    }
+}
+
+impl From<String> for IdRefs {
+    fn from(id: String) -> Self {
+        IdRefs(vec![id])
+    }
+}
+
+impl<S, const N: usize> From<[S; N]> for IdRefs
+where
+    S: Into<String>,
+{
+    fn from(ids: [S; N]) -> Self {
+        IdRefs(ids.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<S> From<Vec<S>> for IdRefs
+where
+    S: Into<String>,
+{
+    fn from(ids: Vec<S>) -> Self {
+        IdRefs(ids.into_iter().map(Into::into).collect())
+    }
+}
+
+impl fmt::Display for IdRefs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, id) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(id)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn hyphenated_names() {
+        let element = div([
+            aria_hidden(true),
+            aria_current(Current::Page),
+            aria_invalid(Invalid::Spelling),
+            aria_live(Live::Polite),
+            aria_relevant([Relevant::Additions, Relevant::Text]),
+            aria_labelledby("title"),
+            aria_valuenow(0.5),
+            aria_label("Close"),
+            aria_haspopup(false),
+        ]);
+        assert_eq!(
+            element.to_html(),
+            r#"<div aria-hidden="true" aria-current="page" aria-invalid="spelling" aria-live="polite" aria-relevant="additions text" aria-labelledby="title" aria-valuenow="0.5" aria-label="Close" aria-haspopup="false"></div>"#
+        );
+    }
+}
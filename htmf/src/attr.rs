@@ -1,27 +1,647 @@
-use std::fmt::Write;
+use std::{borrow::Cow, cell::Cell, fmt::Write, marker::PhantomData};
 
-use crate::escape;
+use crate::{escape, minify, names, render::Syntax, spec::AnyElement};
+
+thread_local! {
+    /// The policy set by [`with_policy`].
+    static POLICY: Cell<AttrPolicy> = const { Cell::new(AttrPolicy::permissive()) };
+}
+
+/// Restrictions for attributes created with [`attr`](crate::declare::attr),
+/// whose names are not known in advance.
+///
+/// By default, any valid attribute name is allowed. The strict policy also rejects
+/// inline event handlers like `onclick`, which a Content Security Policy without
+/// `'unsafe-inline'` would block anyway. Use [`with_policy`] to change the policy:
+///
+/// ```rust,should_panic
+/// use htmf::{
+///     attr::{self, AttrPolicy},
+///     prelude::*,
+/// };
+///
+/// let _policy = attr::with_policy(AttrPolicy::strict());
+///
+/// // Panics
+/// button(attr("onclick", "alert(1)"));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct AttrPolicy {
+    forbid_event_handlers: bool,
+}
+
+impl AttrPolicy {
+    /// Allow all valid attribute names. This is the default.
+    pub const fn permissive() -> AttrPolicy {
+        AttrPolicy {
+            forbid_event_handlers: false,
+        }
+    }
+
+    /// Reject attributes starting with `on`, like `onclick` or `onload`,
+    /// as well as htmx's `hx-on:*` attributes.
+    pub const fn strict() -> AttrPolicy {
+        AttrPolicy {
+            forbid_event_handlers: true,
+        }
+    }
+
+    pub fn is_allowed(&self, name: &str) -> bool {
+        names::is_valid_attribute_name(name) && !self.forbids(name)
+    }
+
+    /// Whether the policy rejects `name`, assuming it is a valid attribute name.
+    fn forbids(&self, name: &str) -> bool {
+        self.forbid_event_handlers && is_event_handler(name)
+    }
+}
+
+/// Use `policy` for the attributes created on the current thread,
+/// until the returned guard is dropped. Dropping it restores the previous policy.
+///
+/// Other threads keep using their own policy, so tests that set a policy don't affect
+/// each other. Set the policy in the thread that builds the elements.
+pub fn with_policy(policy: AttrPolicy) -> AttrPolicyGuard {
+    AttrPolicyGuard {
+        previous: POLICY.replace(policy),
+        _not_send: PhantomData,
+    }
+}
+
+/// Restores the previous [`AttrPolicy`] when dropped. Created by [`with_policy`].
+#[must_use = "the policy is only used until the guard is dropped"]
+pub struct AttrPolicyGuard {
+    previous: AttrPolicy,
+    /// The guard has to be dropped on the thread whose policy it changed.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for AttrPolicyGuard {
+    fn drop(&mut self) {
+        POLICY.set(self.previous);
+    }
+}
+
+fn is_event_handler(name: &str) -> bool {
+    let starts_with = |prefix: &str| {
+        name.get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+    };
+    starts_with("on") || starts_with("hx-on")
+}
+
+/// Check `name` against the HTML attribute name grammar and the active [`AttrPolicy`].
+///
+/// # Panics
+///
+/// If `name` is not allowed.
+pub(crate) fn checked_name(name: Cow<'static, str>) -> Cow<'static, str> {
+    if !names::is_valid_attribute_name(&name) {
+        panic!("invalid attribute name {name:?}");
+    }
+    if POLICY.get().forbids(&name) {
+        panic!("attribute {name:?} is not allowed by the attribute policy");
+    }
+    name
+}
 
 #[derive(PartialEq, Eq, Debug, Clone)]
-pub struct Attr(pub(crate) &'static str, pub(crate) String);
+pub struct Attr(pub(crate) Cow<'static, str>, pub(crate) AttrValue);
 
+/// The value of an attribute, which decides how the attribute is rendered.
 #[derive(PartialEq, Eq, Debug, Clone)]
-pub struct Attrs(pub(crate) Vec<Attr>);
+pub enum AttrValue {
+    /// Rendered as `name="value"`.
+    String(String),
+    /// Rendered as just `name`, for boolean attributes like `disabled`.
+    Present,
+    /// Not rendered at all.
+    Absent,
+}
 
-impl std::fmt::Display for Attrs {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for (i, Attr(k, v)) in self.0.iter().enumerate() {
-            escape::write_escaped_html(f, k);
+impl From<bool> for AttrValue {
+    fn from(value: bool) -> Self {
+        if value {
+            AttrValue::Present
+        } else {
+            AttrValue::Absent
+        }
+    }
+}
+
+/// Marker types for [`IntoAttrValue`], [`IntoClassList`] and [`IntoUrl`](crate::url::IntoUrl).
+///
+/// They allow implementing these traits for both single values and `Option`s
+/// or iterators of them, and are always inferred by the compiler.
+pub mod markers {
+    pub struct Value;
+    pub struct OptionalValue;
+    pub struct Iterable;
+}
+
+/// Values accepted by attribute functions.
+///
+/// Anything implementing `ToString` becomes a string value,
+/// and `None` leaves out the attribute entirely:
+///
+/// ```rust
+/// use htmf::prelude::*;
+///
+/// let tooltip: Option<String> = None;
+/// assert_eq!(div([title_attr(tooltip), tabindex(1)]).to_html(), r#"<div tabindex="1"></div>"#);
+/// ```
+pub trait IntoAttrValue<M> {
+    fn into_attr_value(self) -> AttrValue;
+}
+
+impl<T> IntoAttrValue<markers::Value> for T
+where
+    T: ToString,
+{
+    fn into_attr_value(self) -> AttrValue {
+        AttrValue::String(self.to_string())
+    }
+}
+
+impl<T> IntoAttrValue<markers::OptionalValue> for Option<T>
+where
+    T: ToString,
+{
+    fn into_attr_value(self) -> AttrValue {
+        match self {
+            Some(value) => AttrValue::String(value.to_string()),
+            None => AttrValue::Absent,
+        }
+    }
+}
+
+impl IntoAttrValue<markers::Value> for AttrValue {
+    fn into_attr_value(self) -> AttrValue {
+        self
+    }
+}
+
+/// Values accepted by [`classes`](crate::declare::classes).
+///
+/// This is implemented for strings, `(class, bool)` pairs that only add the class if the
+/// condition is `true`, and anything iterable over these, including `Option`s.
+pub trait IntoClassList<M> {
+    fn push_classes(self, list: &mut String);
+}
+
+impl IntoClassList<markers::Value> for &str {
+    fn push_classes(self, list: &mut String) {
+        for class in self.split_ascii_whitespace() {
+            if !list.is_empty() {
+                list.push(' ');
+            }
+            list.push_str(class);
+        }
+    }
+}
+
+impl IntoClassList<markers::Value> for String {
+    fn push_classes(self, list: &mut String) {
+        self.as_str().push_classes(list)
+    }
+}
+
+impl IntoClassList<markers::Value> for &String {
+    fn push_classes(self, list: &mut String) {
+        self.as_str().push_classes(list)
+    }
+}
+
+impl<T> IntoClassList<markers::Value> for (T, bool)
+where
+    T: IntoClassList<markers::Value>,
+{
+    fn push_classes(self, list: &mut String) {
+        if self.1 {
+            self.0.push_classes(list)
+        }
+    }
+}
+
+impl<I> IntoClassList<markers::Iterable> for I
+where
+    I: IntoIterator,
+    I::Item: IntoClassList<markers::Value>,
+{
+    fn push_classes(self, list: &mut String) {
+        for item in self {
+            item.push_classes(list)
+        }
+    }
+}
+
+/// A list of attributes.
+///
+/// Adding an attribute whose name is already present merges it with the earlier one:
+///
+/// - `class` values are joined with spaces,
+/// - `style` declarations are joined with `;`,
+/// - for all other attributes, the last value wins, but keeps the position of the first one.
+///
+/// A `None` value for `class` or `style` leaves the earlier value untouched,
+/// while for other attributes it removes the earlier value.
+/// With the "tailwind" feature, merged `class` values also drop conflicting Tailwind utilities.
+///
+/// Names are compared exactly. HTML elements treat attribute names as ASCII case-insensitive
+/// though, so in HTML output their names are lowercased, and attributes whose names only
+/// differ in case are merged the same way. SVG and MathML elements as well as
+/// [XML output](crate::xml) keep names as they are.
+///
+/// ```rust
+/// use htmf::prelude::*;
+///
+/// assert_eq!(
+///     div([class("a"), id("first"), class("b"), id("second")]).to_html(),
+///     r#"<div class="a b" id="second"></div>"#
+/// );
+/// ```
+///
+/// `E` is the [element type](crate::spec) the attributes are checked against.
+/// Attribute functions are generic over it, so it is usually inferred from the tag function
+/// the attributes are passed to. `Attrs<AnyElement>` accepts all attributes, and can be
+/// passed to any element using [`Attrs::unchecked`].
+pub struct Attrs<E = AnyElement>(pub(crate) Vec<Attr>, PhantomData<fn() -> E>);
+
+impl Attr {
+    pub(crate) fn write_html<W>(&self, f: &mut W) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        self.write_html_in(f, Syntax::Html)
+    }
+
+    /// Whether this attribute shows up in the output at all.
+    pub(crate) fn is_rendered(&self) -> bool {
+        self.1 != AttrValue::Absent
+    }
+
+    /// Write this attribute, assuming [`Attr::is_rendered`] is true.
+    pub(crate) fn write_html_in<W>(&self, f: &mut W, syntax: Syntax) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        let Attr(k, v) = self;
+        escape::write_escaped_html(f, k)?;
+
+        let v = match v {
+            AttrValue::String(v) => v.as_str(),
+            // XML has no boolean attributes, so repeat the name as value like XHTML does.
+            AttrValue::Present if syntax == Syntax::Xml => &**k,
+            AttrValue::Present | AttrValue::Absent => return Ok(()),
+        };
+
+        if syntax == Syntax::Xml {
             f.write_char('=')?;
             f.write_char('"')?;
-            escape::write_escaped_html(f, v);
-            f.write_char('"')?;
+            escape::write_escaped_xml_attribute(f, v)?;
+            return f.write_char('"');
+        }
 
-            if i < self.0.len() - 1 {
-                f.write_char(' ')?;
+        if syntax == Syntax::Minified {
+            // `disabled=""` and `disabled` are the same. Other values are kept, because they
+            // show up in the DOM, like `disabled="disabled"` or `hidden="until-found"`.
+            if v.is_empty() && minify::is_boolean_attribute(k) {
+                return Ok(());
+            }
+            if minify::can_unquote_attribute_value(v) {
+                f.write_char('=')?;
+                return escape::write_escaped_html(f, v);
+            }
+        }
+
+        f.write_char('=')?;
+        f.write_char('"')?;
+        escape::write_escaped_html(f, v)?;
+        f.write_char('"')
+    }
+}
+
+impl<E> Attrs<E> {
+    pub(crate) fn new(attrs: Vec<Attr>) -> Attrs<E> {
+        Attrs(attrs, PhantomData)
+    }
+
+    /// Change the element type without checking the attributes against it.
+    pub(crate) fn cast<F>(self) -> Attrs<F> {
+        Attrs(self.0, PhantomData)
+    }
+
+    /// Add `attr`, merging it with an existing attribute of the same name.
+    pub(crate) fn push(&mut self, attr: Attr) {
+        let Attr(name, value) = attr;
+        let Some(existing) = self.0.iter_mut().find(|existing| existing.0 == name) else {
+            self.0.push(Attr(name, value));
+            return;
+        };
+
+        let separator = match &*name {
+            "class" => " ",
+            "style" => ";",
+            _ => {
+                existing.1 = value;
+                return;
             }
+        };
+
+        existing.1 = match (std::mem::replace(&mut existing.1, AttrValue::Absent), value) {
+            (old, AttrValue::Absent) => old,
+            (AttrValue::String(old), AttrValue::String(new)) => {
+                let old = match separator {
+                    ";" => old.trim_end().trim_end_matches(';'),
+                    _ => old.trim_end(),
+                };
+                let new = new.trim_start();
+                let merged = if old.is_empty() {
+                    new.to_string()
+                } else if new.is_empty() {
+                    old.to_string()
+                } else {
+                    format!("{old}{separator}{new}")
+                };
+                #[cfg(feature = "tailwind")]
+                let merged = match &*name {
+                    "class" => crate::tailwind::merge(&merged),
+                    _ => merged,
+                };
+                AttrValue::String(merged)
+            }
+            (AttrValue::String(old), AttrValue::Present) => AttrValue::String(old),
+            (_, new) => new,
+        };
+    }
+
+    /// The value of the attribute called `name`, compared case-insensitively.
+    /// If several names match, the last one wins like in HTML output.
+    pub(crate) fn get(&self, name: &str) -> Option<&AttrValue> {
+        self.0
+            .iter()
+            .rev()
+            .find(|attr| attr.0.eq_ignore_ascii_case(name))
+            .map(|attr| &attr.1)
+    }
+
+    /// The attributes that show up in the output.
+    pub(crate) fn rendered(&self) -> impl Iterator<Item = &Attr> {
+        self.0.iter().filter(|attr| attr.is_rendered())
+    }
+
+    /// These attributes as written in `syntax` on an element, which is an SVG or MathML
+    /// element if `foreign` is true: names of HTML elements in HTML output are lowercased,
+    /// merging attributes whose names only differ in case.
+    pub(crate) fn for_syntax(&self, syntax: Syntax, foreign: bool) -> Cow<'_, Attrs<E>> {
+        let has_uppercase = |name: &str| name.bytes().any(|b| b.is_ascii_uppercase());
+        if syntax == Syntax::Xml || foreign || !self.0.iter().any(|attr| has_uppercase(&attr.0)) {
+            return Cow::Borrowed(self);
+        }
+        Cow::Owned(
+            self.0
+                .iter()
+                .map(|Attr(name, value)| {
+                    let name = match has_uppercase(name) {
+                        true => Cow::Owned(name.to_ascii_lowercase()),
+                        false => name.clone(),
+                    };
+                    Attr(name, value.clone())
+                })
+                .collect(),
+        )
+    }
+
+    /// Write all rendered attributes, each preceded by a space,
+    /// on an element that is an SVG or MathML element if `foreign` is true.
+    pub(crate) fn write_html_in<W>(
+        &self,
+        f: &mut W,
+        syntax: Syntax,
+        foreign: bool,
+    ) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        for attr in self.for_syntax(syntax, foreign).rendered() {
+            f.write_char(' ')?;
+            attr.write_html_in(f, syntax)?;
         }
 
         std::fmt::Result::Ok(())
     }
 }
+
+impl Attrs<AnyElement> {
+    /// Use these attributes for elements of type `E` without checking them,
+    /// like the return value of a helper function:
+    ///
+    /// ```rust
+    /// use htmf::{attr::Attrs, into_attrs::IntoAttrs, prelude::*};
+    ///
+    /// fn card() -> Attrs {
+    ///     let attrs: [Attrs; 2] = [class("card"), attr("data-kind", "card")];
+    ///     attrs.into_attrs()
+    /// }
+    ///
+    /// assert_eq!(
+    ///     a([card().unchecked(), href("/")]).to_html(),
+    ///     r#"<a class="card" data-kind="card" href="/"></a>"#
+    /// );
+    /// ```
+    ///
+    /// `Attrs<AnyElement>` doesn't convert into the attributes of other elements implicitly,
+    /// because the element type of attribute functions like `class` couldn't be inferred then.
+    pub fn unchecked<E>(self) -> Attrs<E> {
+        self.cast()
+    }
+}
+
+impl<E> Clone for Attrs<E> {
+    fn clone(&self) -> Self {
+        Attrs::new(self.0.clone())
+    }
+}
+
+impl<E> PartialEq for Attrs<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<E> Eq for Attrs<E> {}
+
+impl<E> std::fmt::Debug for Attrs<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Attrs").field(&self.0).finish()
+    }
+}
+
+impl<E> From<Attr> for Attrs<E> {
+    fn from(attr: Attr) -> Self {
+        Attrs::new(vec![attr])
+    }
+}
+
+impl<E> Extend<Attr> for Attrs<E> {
+    fn extend<T: IntoIterator<Item = Attr>>(&mut self, iter: T) {
+        for attr in iter {
+            self.push(attr);
+        }
+    }
+}
+
+impl<E> FromIterator<Attr> for Attrs<E> {
+    fn from_iter<T: IntoIterator<Item = Attr>>(iter: T) -> Self {
+        let mut attrs = Attrs::new(Vec::new());
+        attrs.extend(iter);
+        attrs
+    }
+}
+
+impl<E> std::fmt::Display for Attrs<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, attr) in self.rendered().enumerate() {
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+            attr.write_html(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{with_policy, AttrPolicy, Attrs};
+    use crate::{into_attrs::IntoAttrs, prelude::*, pretty::PrettyOptions};
+
+    #[test]
+    fn merge_class() {
+        let active = false;
+        let attrs: [Attrs; 4] = [
+            class("flex "),
+            class(active.then_some("active")),
+            class("gap-2"),
+            class(""),
+        ];
+        let attrs = attrs.into_attrs();
+        assert_eq!(attrs.to_string(), r#"class="flex gap-2""#);
+    }
+
+    #[test]
+    fn merge_style() {
+        let attrs: [Attrs; 2] = [style("color: red;"), style("margin: 0")];
+        let attrs = attrs.into_attrs();
+        assert_eq!(attrs.to_string(), r#"style="color: red;margin: 0""#);
+    }
+
+    #[test]
+    fn last_wins() {
+        let attrs: [Attrs; 4] = [id("a"), title_attr("t"), id("b"), title_attr(None::<&str>)];
+        let attrs = attrs.into_attrs();
+        assert_eq!(attrs.to_string(), r#"id="b""#);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid attribute name")]
+    fn invalid_attribute_name() {
+        let _: Attrs = attr("x onload", "alert(1)");
+    }
+
+    #[test]
+    fn event_handler_policy() {
+        assert!(AttrPolicy::permissive().is_allowed("onclick"));
+        assert!(!AttrPolicy::strict().is_allowed("onclick"));
+        assert!(!AttrPolicy::strict().is_allowed("ONLOAD"));
+        assert!(!AttrPolicy::strict().is_allowed("hx-on:click"));
+        assert!(AttrPolicy::strict().is_allowed("data-on"));
+        assert!(!AttrPolicy::strict().is_allowed("a=b"));
+    }
+
+    #[test]
+    fn scoped_policy() {
+        let handler = || div(attr("onclick", "go()")).to_html();
+        {
+            let _strict = with_policy(AttrPolicy::strict());
+            assert!(std::panic::catch_unwind(handler).is_err());
+            assert_eq!(
+                std::thread::spawn(handler).join().unwrap(),
+                r#"<div onclick="go()"></div>"#
+            );
+        }
+        assert_eq!(handler(), r#"<div onclick="go()"></div>"#);
+    }
+
+    fn card() -> Attrs {
+        let attrs: [Attrs; 2] = [class("card"), href("/cards")];
+        attrs.into_attrs()
+    }
+
+    #[test]
+    fn unchecked_helpers() {
+        assert_eq!(
+            div(card().unchecked()).to_html(),
+            r#"<div class="card" href="/cards"></div>"#
+        );
+        assert_eq!(
+            a([card().unchecked(), id("first")]).to_html(),
+            r#"<a class="card" href="/cards" id="first"></a>"#
+        );
+    }
+
+    #[test]
+    fn element_attr() {
+        let element = div(class("a")).attr("class", "b").attr("data-x", "1");
+        assert_eq!(element.to_html(), r#"<div class="a b" data-x="1"></div>"#);
+    }
+
+    #[test]
+    fn html_names_are_case_insensitive() {
+        let element = div(class("a"))
+            .attr("CLASS", "b")
+            .attr("Title", "x")
+            .attr("data-Foo", "1")
+            .attr("data-foo", "2");
+        assert_eq!(
+            element.to_html(),
+            r#"<div class="a b" title="x" data-foo="2"></div>"#
+        );
+        assert_eq!(
+            element.to_html_minified(),
+            r#"<div class="a b" title=x data-foo=2></div>"#
+        );
+    }
+
+    #[test]
+    fn xml_names_keep_their_case() {
+        let element = custom_tag("item", []).attr("ID", "1").attr("id", "2");
+        assert_eq!(element.to_xml(), r#"<item ID="1" id="2"/>"#);
+        assert_eq!(element.to_html(), r#"<item id="2"></item>"#);
+    }
+
+    #[test]
+    fn other_names_are_case_sensitive() {
+        let element = custom_tag("svg", [attr("viewBox", "0 0 10 10"), attr("viewbox", "1")])
+            .attr("viewBox", "0 0 20 20");
+        assert_eq!(
+            element.to_html(),
+            r#"<svg viewBox="0 0 20 20" viewbox="1"></svg>"#
+        );
+
+        let nested = div([]).with(
+            custom_tag("svg", attr("viewBox", "0 0 1 1"))
+                .with(custom_tag("foreignObject", []).with(span(attr("Title", "x")))),
+        );
+        let expected = r#"<div><svg viewBox="0 0 1 1"><foreignObject><span title="x"></span></foreignObject></svg></div>"#;
+        assert_eq!(nested.to_html(), expected);
+        let pretty = nested
+            .to_html_pretty_with(&PrettyOptions::default().max_line_width(10))
+            .unwrap();
+        assert!(pretty.contains(r#"viewBox="0 0 1 1""#), "{pretty}");
+        assert!(pretty.contains(r#"title="x""#), "{pretty}");
+    }
+}
@@ -1,17 +1,24 @@
-use crate::{attr::Attrs, element::Element, into_elements::IntoElements};
+use std::borrow::Cow;
+
+use crate::{
+    attr::{Attrs, IntoAttrValue},
+    element::Element,
+    into_elements::IntoElements,
+    pretty::PrettyError,
+};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub(crate) enum Path {
     Top,
     Tag {
-        tag: &'static str,
+        tag: Cow<'static, str>,
         attrs: Attrs,
         left: Vec<Element>,
         parent: Box<Path>,
         right: Vec<Element>,
     },
     LeafTag {
-        tag: &'static str,
+        tag: Cow<'static, str>,
         attrs: Attrs,
         parent: Box<Path>,
     },
@@ -46,9 +53,10 @@ impl Builder {
         self
     }
 
-    pub fn attr<C>(mut self, name: &'static str, value: C) -> Builder
+    pub fn attr<N, C, M>(mut self, name: N, value: C) -> Builder
     where
-        C: Into<String>,
+        N: Into<Cow<'static, str>>,
+        C: IntoAttrValue<M>,
     {
         self.element = self.element.attr(name, value);
         self
@@ -56,7 +64,7 @@ impl Builder {
 
     pub(crate) fn into_new_child_tag(self, new_tag: &'static str, attrs: Attrs) -> Builder {
         let new_element = Element::Tag {
-            tag: new_tag,
+            tag: Cow::Borrowed(new_tag),
             attrs,
             children: Vec::new(),
         };
@@ -92,6 +100,9 @@ impl Builder {
                 right: Vec::new(),
             },
             Element::Text { .. } => return self,
+            Element::Raw { .. } => return self,
+            Element::CData { .. } => return self,
+            Element::ProcessingInstruction { .. } => return self,
             Element::Nothing => return self,
         };
 
@@ -175,21 +186,14 @@ impl Builder {
         root.to_html()
     }
 
-    #[cfg(feature = "pretty-print")]
-    pub fn to_html_pretty(
-        self,
-    ) -> Result<String, markup_fmt::FormatError<std::convert::Infallible>> {
-        let raw = self.to_html();
-        markup_fmt::format_text(
-            &raw,
-            markup_fmt::Language::Html,
-            &markup_fmt::config::FormatOptions::default(),
-            |code, _| Ok::<_, std::convert::Infallible>(code.into()),
-        )
-        .inspect_err(|e| {
-            println!("{raw}");
-            println!("{e}");
-        })
+    pub fn to_html_minified(self) -> String {
+        let root = self.into_root_element();
+        root.to_html_minified()
+    }
+
+    pub fn to_html_pretty(self) -> Result<String, PrettyError> {
+        let root = self.into_root_element();
+        root.to_html_pretty()
     }
 }
 
@@ -0,0 +1,266 @@
+//! Custom [`data-*`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/data-*)
+//! attributes for passing values to scripts.
+//!
+//! Keys are written without the `data-` prefix. Like with the `dataset` property in JavaScript,
+//! camelCase keys are converted to kebab-case, so `userId` becomes `data-user-id`.
+//!
+//! Everything in this module is also exported by the [`declare`](crate::declare) and
+//! [`declare_inline`](crate::declare_inline) modules.
+
+use std::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+#[cfg(feature = "serde")]
+use crate::json;
+use crate::{
+    attr::{self, Attr, Attrs, IntoAttrValue},
+    into_attrs::IntoAttrs,
+};
+
+/// The `data-key` attribute.
+///
+/// ```rust
+/// use htmf::prelude::*;
+///
+/// let row = tr([data_attr("userId", 42), data_attr("role", "admin")]);
+/// assert_eq!(row.to_html(), r#"<tr data-user-id="42" data-role="admin"></tr>"#);
+/// ```
+///
+/// # Panics
+///
+/// If `key` is empty or doesn't form a valid attribute name.
+pub fn data_attr<E, C, M>(key: &str, value: C) -> Attrs<E>
+where
+    C: IntoAttrValue<M>,
+{
+    Attr(data_name(key), value.into_attr_value()).into()
+}
+
+/// The `data-key` attribute, with `value` serialized as JSON.
+///
+/// ```rust
+/// use htmf::prelude::*;
+///
+/// let config = serde_json::json!({ "theme": "dark", "ids": [1, 2] });
+/// assert_eq!(
+///     div(data_json("config", &config)).to_html(),
+///     r#"<div data-config="{&quot;ids&quot;:[1,2],&quot;theme&quot;:&quot;dark&quot;}"></div>"#
+/// );
+/// ```
+///
+/// # Panics
+///
+/// If `key` is empty or doesn't form a valid attribute name, or if `value` can't be
+/// serialized as JSON. Use [`try_data_json`] to handle serialization errors instead.
+#[cfg(feature = "serde")]
+pub fn data_json<E, T>(key: &str, value: &T) -> Attrs<E>
+where
+    T: Serialize + ?Sized,
+{
+    json::json_attr(data_name(key), value)
+}
+
+/// Like [`data_json`], but returns the error if `value` can't be serialized as JSON.
+///
+/// # Panics
+///
+/// If `key` is empty or doesn't form a valid attribute name.
+#[cfg(feature = "serde")]
+pub fn try_data_json<E, T>(key: &str, value: &T) -> Result<Attrs<E>, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    json::try_json_attr(data_name(key), value)
+}
+
+/// A set of `data-*` attributes built at runtime.
+///
+/// It can be collected from any iterator of key-value pairs, like a `HashMap`:
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use htmf::{data::DataAttrs, prelude::*};
+///
+/// let state = BTreeMap::from([("page", 2), ("perPage", 20)]);
+/// let list = ul(state.into_iter().collect::<DataAttrs>());
+/// assert_eq!(list.to_html(), r#"<ul data-page="2" data-per-page="20"></ul>"#);
+/// ```
+///
+/// # Panics
+///
+/// Like [`data_attr`], adding a key that is empty or doesn't form a valid attribute name panics.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct DataAttrs(Attrs);
+
+impl DataAttrs {
+    pub fn new() -> DataAttrs {
+        DataAttrs(Attrs::new(Vec::new()))
+    }
+
+    /// Add the `data-key` attribute. Adding a key again replaces its earlier value.
+    pub fn set<C, M>(mut self, key: &str, value: C) -> DataAttrs
+    where
+        C: IntoAttrValue<M>,
+    {
+        self.0.push(Attr(data_name(key), value.into_attr_value()));
+        self
+    }
+
+    /// Add the `data-key` attribute, with `value` serialized as JSON.
+    ///
+    /// # Panics
+    ///
+    /// If `value` can't be serialized as JSON. Use [`DataAttrs::try_json`] to handle
+    /// the error instead.
+    #[cfg(feature = "serde")]
+    pub fn json<T>(mut self, key: &str, value: &T) -> DataAttrs
+    where
+        T: Serialize + ?Sized,
+    {
+        let attrs: Attrs = data_json(key, value);
+        self.0.extend(attrs.0);
+        self
+    }
+
+    /// Like [`DataAttrs::json`], but returns the error if `value` can't be serialized as JSON.
+    #[cfg(feature = "serde")]
+    pub fn try_json<T>(mut self, key: &str, value: &T) -> Result<DataAttrs, serde_json::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let attrs: Attrs = try_data_json(key, value)?;
+        self.0.extend(attrs.0);
+        Ok(self)
+    }
+
+    /// Whether no attribute would be rendered. Keys set to `None` don't count.
+    pub fn is_empty(&self) -> bool {
+        self.0.rendered().next().is_none()
+    }
+}
+
+impl Default for DataAttrs {
+    fn default() -> Self {
+        DataAttrs::new()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for DataAttrs
+where
+    K: AsRef<str>,
+    V: ToString,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(DataAttrs::new(), |data, (key, value)| {
+                data.set(key.as_ref(), value.to_string())
+            })
+    }
+}
+
+impl<E> From<DataAttrs> for Attrs<E> {
+    fn from(data: DataAttrs) -> Self {
+        data.0.cast()
+    }
+}
+
+impl<E> IntoAttrs<E> for DataAttrs {
+    fn into_attrs(self) -> Attrs {
+        self.0
+    }
+}
+
+/// Build the attribute name for the data key `key`.
+///
+/// # Panics
+///
+/// If `key` is empty or the name is not allowed by [`attr::checked_name`].
+fn data_name(key: &str) -> Cow<'static, str> {
+    if key.is_empty() {
+        panic!("data attribute key must not be empty");
+    }
+    let mut name = String::with_capacity(key.len() + 5);
+    name.push_str("data-");
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            name.push('-');
+            name.push(c.to_ascii_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    attr::checked_name(Cow::Owned(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn keys() {
+        let element = div([
+            data_attr("id", 1),
+            data_attr("fooBarBaz", "x"),
+            data_attr("already-kebab", "\"quoted\""),
+            data_attr("missing", None::<&str>),
+            DataAttrs::new().set("a", 1).set("a", 2).into(),
+        ]);
+        assert_eq!(
+            element.to_html(),
+            r#"<div data-id="1" data-foo-bar-baz="x" data-already-kebab="&quot;quoted&quot;" data-a="2"></div>"#
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(DataAttrs::new().is_empty());
+        assert!(DataAttrs::new().set("a", None::<&str>).is_empty());
+        assert!(!DataAttrs::new()
+            .set("a", None::<&str>)
+            .set("b", 1)
+            .is_empty());
+        assert!(DataAttrs::new()
+            .set("a", 1)
+            .set("a", None::<&str>)
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid attribute name")]
+    fn invalid_key() {
+        let _: Attrs = data_attr("a b", "");
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be empty")]
+    fn empty_key() {
+        DataAttrs::new().set("", "");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_errors() {
+        let invalid = std::collections::BTreeMap::from([((1, 2), 3)]);
+        assert!(DataAttrs::new().try_json("state", &invalid).is_err());
+        assert!(try_data_json::<crate::spec::AnyElement, _>("state", &invalid).is_err());
+        let attrs = DataAttrs::new().try_json("ids", &[1, 2]).unwrap();
+        assert_eq!(div(attrs).to_html(), r#"<div data-ids="[1,2]"></div>"#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_escaping() {
+        let value = serde_json::json!({ "html": "</div><script>", "quote": "'\"" });
+        let element = div(DataAttrs::new().json("state", &value));
+        assert_eq!(
+            element.to_html(),
+            r#"<div data-state="{&quot;html&quot;:&quot;&lt;/div&gt;&lt;script&gt;&quot;,&quot;quote&quot;:&quot;&#x27;\&quot;&quot;}"></div>"#
+        );
+    }
+}
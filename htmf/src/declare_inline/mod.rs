@@ -1,22 +1,29 @@
 mod all_tags;
 
 pub use super::declare::all_attrs::*;
+pub use crate::aria::*;
+pub use crate::data::*;
 pub use all_tags::*;
 
+use std::borrow::Cow;
+
 use crate::{
-    attr::{Attr, Attrs},
-    element::Element,
+    attr::{self, Attr, Attrs, IntoAttrValue},
+    element::{Element, Text, TrustedHtml, VoidElement},
+    into_attrs::IntoAttrs,
     into_elements::IntoElements,
+    names,
 };
 
-pub fn text<C>(value: C) -> Element
+pub fn text<C>(value: C) -> Text
 where
     C: Into<String>,
 {
-    Element::Text { text: value.into() }
+    Text(value.into())
 }
 
 /// Prepend `<!doctype html>` to the given children.
+/// In [XML output](crate::xml), the XML declaration and doctype are prepended instead.
 pub fn document<Children: IntoElements>(children: Children) -> Element {
     Element::Document {
         children: children.into_elements(),
@@ -29,13 +36,115 @@ pub fn fragment<Children: IntoElements>(children: Children) -> Element {
     }
 }
 
+/// Insert `value` into the document as-is, without escaping it.
+///
+/// Only use this for HTML that is known to be safe, such as the output of a markdown renderer
+/// or a sanitizer. See [`TrustedHtml`].
+pub fn trusted_html_unchecked<C>(value: C) -> TrustedHtml
+where
+    C: Into<String>,
+{
+    TrustedHtml::new_unchecked(value)
+}
+
+/// Text that is written as a CDATA section in [XML output](crate::xml),
+/// like the HTML contents of an RSS item. In HTML output, it is written as normal text.
+pub fn cdata<C>(value: C) -> Element
+where
+    C: Into<String>,
+{
+    Element::CData { text: value.into() }
+}
+
+/// A processing instruction like `<?xml-stylesheet href="feed.xsl" type="text/xsl"?>`,
+/// for [XML output](crate::xml).
+///
+/// `data` is written as-is. HTML parsers turn processing instructions into comments
+/// that end at the first `>`, so `data` can't contain it.
+///
+/// # Panics
+///
+/// If `target` is not a valid XML name or is reserved, like `xml`,
+/// or if `data` contains `>`.
+pub fn processing_instruction<T, C>(target: T, data: C) -> Element
+where
+    T: Into<Cow<'static, str>>,
+    C: Into<String>,
+{
+    let target = target.into();
+    let data = data.into();
+    assert!(
+        names::is_valid_xml_name(&target) && !target.eq_ignore_ascii_case("xml"),
+        "invalid processing instruction target {target:?}"
+    );
+    assert!(
+        !data.contains('>'),
+        "processing instruction data must not contain \">\""
+    );
+    Element::ProcessingInstruction { target, data }
+}
+
 pub fn nothing() -> Element {
     Element::Nothing
 }
 
-pub fn attr<C>(name: &'static str, value: C) -> Attrs
+/// Create an element whose name is only known at runtime, like the web component `<my-widget>`.
+///
+/// `name` must be a [valid custom element name](https://html.spec.whatwg.org/multipage/custom-elements.html#valid-custom-element-name),
+/// or consist of ASCII letters and digits.
+///
+/// # Panics
+///
+/// If `name` is not a valid tag name.
+pub fn custom_tag<N, A, Children>(name: N, attrs: A, with: Children) -> Element
 where
-    C: Into<String>,
+    N: Into<Cow<'static, str>>,
+    A: IntoAttrs,
+    Children: IntoElements,
+{
+    Element::Tag {
+        tag: valid_tag_name(name.into()),
+        attrs: attrs.into_attrs(),
+        children: with.into_elements(),
+    }
+}
+
+/// Like [`custom_tag`], but for elements without children or a closing tag.
+///
+/// Browsers only treat void elements like `<br>` as closed without a closing tag,
+/// so this should not be used for custom elements in HTML documents.
+///
+/// # Panics
+///
+/// If `name` is not a valid tag name.
+pub fn custom_leaf_tag<N, A>(name: N, attrs: A) -> VoidElement
+where
+    N: Into<Cow<'static, str>>,
+    A: IntoAttrs,
+{
+    VoidElement {
+        tag: valid_tag_name(name.into()),
+        attrs: attrs.into_attrs(),
+    }
+}
+
+fn valid_tag_name(name: Cow<'static, str>) -> Cow<'static, str> {
+    assert!(names::is_valid_tag_name(&name), "invalid tag name {name:?}");
+    name
+}
+
+/// An attribute that has no dedicated function, like `data-*` attributes.
+///
+/// Unlike the attribute functions, it is accepted by all elements.
+///
+/// # Panics
+///
+/// If `name` is not a valid attribute name, or not allowed by the active
+/// [`AttrPolicy`](crate::attr::AttrPolicy).
+pub fn attr<E, N, C, M>(name: N, value: C) -> Attrs<E>
+where
+    N: Into<Cow<'static, str>>,
+    C: IntoAttrValue<M>,
 {
-    Attrs(vec![Attr(name, value.into())])
+    Attr(attr::checked_name(name.into()), value.into_attr_value()).into()
 }
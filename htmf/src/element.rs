@@ -1,20 +1,26 @@
-use std::fmt::{Debug, Write};
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Write},
+};
 
 use crate::{
-    attr::{Attr, Attrs},
+    attr::{self, Attr, Attrs, IntoAttrValue},
     declare, escape,
     into_elements::IntoElements,
+    minify,
+    render::{self, Context, IoWriter, Syntax, TextMode},
+    validate,
 };
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Element {
     Tag {
         children: Vec<Element>,
-        tag: &'static str,
+        tag: Cow<'static, str>,
         attrs: Attrs,
     },
     LeafTag {
-        tag: &'static str,
+        tag: Cow<'static, str>,
         attrs: Attrs,
     },
     Fragment {
@@ -26,31 +32,138 @@ pub enum Element {
     Text {
         text: String,
     },
+    /// HTML that is written as-is, without escaping. Create it from a [`TrustedHtml`].
+    Raw {
+        html: String,
+    },
+    /// Text that is written as a CDATA section in [XML output](crate::xml),
+    /// and as normal text in HTML. Create it using [`cdata`](crate::declare::cdata).
+    CData {
+        text: String,
+    },
+    /// A processing instruction like `<?xml-stylesheet href="feed.xsl"?>`. Create it using
+    /// [`processing_instruction`](crate::declare::processing_instruction).
+    ProcessingInstruction {
+        target: Cow<'static, str>,
+        data: String,
+    },
     Nothing,
 }
 
+/// An element without children or a closing tag, like `<br>` or `<input>`.
+///
+/// Void tag functions return this instead of an [`Element`], so adding children to them
+/// doesn't compile:
+///
+/// ```rust,compile_fail
+/// use htmf::prelude::*;
+///
+/// input([]).with(text("x"));
+/// ```
+///
+/// Convert it into an [`Element`] to combine it with other elements.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct VoidElement {
+    pub(crate) tag: Cow<'static, str>,
+    pub(crate) attrs: Attrs,
+}
+
+impl VoidElement {
+    pub fn to_html(&self) -> String {
+        self.to_string()
+    }
+
+    /// Add an attribute to this element, like [`Element::attr`] does.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is not a valid attribute name, or not allowed by the active
+    /// [`AttrPolicy`](crate::attr::AttrPolicy).
+    pub fn attr<N, C, M>(mut self, name: N, value: C) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        C: IntoAttrValue<M>,
+    {
+        let name = attr::checked_name(name.into());
+        self.attrs.push(Attr(name, value.into_attr_value()));
+        self
+    }
+}
+
+/// A text node, created with [`text`](crate::declare::text).
+///
+/// Text has no attributes, so there is no `attr` method.
+/// Convert it into an [`Element`] to combine it with other elements.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Text(pub(crate) String);
+
+impl Text {
+    pub fn to_html(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A string of HTML that is trusted to be safe, such as rendered markdown or a cached fragment.
+///
+/// Its contents are written to the output without any escaping,
+/// so never construct it from unsanitized user input.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TrustedHtml(String);
+
+impl TrustedHtml {
+    /// Mark `html` as trusted.
+    /// The caller is responsible for making sure it is well-formed and free of injected markup.
+    pub fn new_unchecked<C>(html: C) -> TrustedHtml
+    where
+        C: Into<String>,
+    {
+        TrustedHtml(html.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
 impl Element {
     pub fn to_html(&self) -> String {
         self.to_string()
     }
 
-    #[cfg(feature = "pretty-print")]
-    pub fn to_html_pretty(
-        &self,
-    ) -> Result<String, markup_fmt::FormatError<std::convert::Infallible>> {
-        let raw = self.to_html();
-        markup_fmt::format_text(
-            &raw,
-            markup_fmt::Language::Html,
-            &markup_fmt::config::FormatOptions::default(),
-            |code, _| Ok::<_, std::convert::Infallible>(code.into()),
-        )
-        .inspect_err(|e| {
-            println!("{raw}");
-            println!("{e}");
-        })
+    /// Append the HTML for this element to `buf`.
+    ///
+    /// Clear and reuse the same buffer to avoid allocating a new `String`
+    /// for every render.
+    pub fn render_into(&self, buf: &mut String) {
+        self.write_html(buf)
+            .expect("writing HTML to a String should never fail");
     }
 
+    /// Stream the HTML for this element into an [`std::io::Write`] sink,
+    /// such as a file or a socket, without building an intermediate `String`.
+    ///
+    /// Consider wrapping unbuffered sinks in a [`std::io::BufWriter`].
+    pub fn write_to<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: std::io::Write + ?Sized,
+    {
+        let mut adapter = IoWriter::new(writer);
+        let result = self.write_html(&mut adapter);
+        adapter.finish(result)
+    }
+
+    /// Append children to this element.
+    ///
+    /// Elements without children, like [`Element::LeafTag`] and [`Element::Text`],
+    /// stay unchanged.
     pub fn with<C>(mut self, value: C) -> Self
     where
         C: IntoElements,
@@ -62,80 +175,228 @@ impl Element {
         self
     }
 
-    pub fn attr<C>(mut self, name: &'static str, value: C) -> Self
+    /// Add an attribute to this element, like [`attr`](crate::declare::attr) does.
+    /// Elements without attributes, like [`Element::Text`], stay unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is not a valid attribute name, or not allowed by the active
+    /// [`AttrPolicy`](crate::attr::AttrPolicy).
+    pub fn attr<N, C, M>(mut self, name: N, value: C) -> Self
     where
-        C: Into<String>,
+        N: Into<Cow<'static, str>>,
+        C: IntoAttrValue<M>,
     {
+        let name = attr::checked_name(name.into());
         if let Some(attrs) = self.attrs_mut() {
-            attrs.push(Attr(name, value.into()));
+            attrs.push(Attr(name, value.into_attr_value()));
         }
         self
     }
 
-    pub fn write_html(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// Write the HTML for this element into any [`std::fmt::Write`] sink,
+    /// such as a `String` or a [`std::fmt::Formatter`].
+    pub fn write_html<W>(&self, f: &mut W) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        validate::debug_assert_valid(self);
+        self.write_html_in(f, Context::new(Syntax::Html))
+    }
+
+    /// Render HTML that is as small as possible while still parsing to the same DOM.
+    ///
+    /// This omits optional start and end tags such as `</li>` or `</p>`,
+    /// leaves attribute values unquoted where possible,
+    /// and writes boolean attributes like `disabled` without a value.
+    pub fn to_html_minified(&self) -> String {
+        let mut buf = String::new();
+        self.write_html_minified(&mut buf)
+            .expect("writing HTML to a String should never fail");
+        buf
+    }
+
+    /// Write the minified HTML for this element into any [`std::fmt::Write`] sink.
+    /// See [`Element::to_html_minified`].
+    pub fn write_html_minified<W>(&self, f: &mut W) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        validate::debug_assert_valid(self);
+        self.write_html_in(f, Context::new(Syntax::Minified))
+    }
+
+    pub(crate) fn write_html_in<W>(&self, f: &mut W, cx: Context<'_>) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        self.write_opening_html(f, cx)?;
+
+        if let Element::Tag { children, .. }
+        | Element::Fragment { children }
+        | Element::Document { children } = self
+        {
+            Self::write_children_html(f, children, self.context_for_children(cx))?;
+        }
+
+        self.write_closing_html(f, cx)
+    }
+
+    /// The context that this element's children are written in,
+    /// given the context `cx` of this element itself.
+    pub(crate) fn context_for_children<'a>(&'a self, cx: Context<'a>) -> Context<'a> {
+        match self {
+            Element::Tag { tag, .. } => Context {
+                text_mode: TextMode::for_tag(tag),
+                parent: Some(tag),
+                next_sibling: None,
+                in_foreign_content: render::children_in_foreign_content(tag, cx.in_foreign_content),
+                ..cx
+            },
+            Element::Fragment { .. } => Context {
+                next_sibling: None,
+                ..cx
+            },
+            _ => Context {
+                text_mode: TextMode::Escaped,
+                parent: None,
+                next_sibling: None,
+                ..cx
+            },
+        }
+    }
+
+    /// Write everything that comes before this element's children.
+    /// For elements without children, this writes the whole element.
+    pub(crate) fn write_opening_html<W>(&self, f: &mut W, cx: Context<'_>) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
         match self {
             Element::Tag {
                 children,
                 tag,
                 attrs,
             } => {
-                f.write_char('<')?;
-                escape::write_escaped_html(f, tag);
-
-                if !attrs.0.is_empty() {
-                    f.write_char(' ')?
-                };
-
-                std::fmt::Display::fmt(attrs, f)?;
-
-                f.write_char('>')?;
-
-                Self::write_children_html(f, children)?;
+                if cx.syntax == Syntax::Minified && minify::can_omit_start_tag(tag, attrs, children)
+                {
+                    return Ok(());
+                }
 
                 f.write_char('<')?;
-                f.write_char('/')?;
-                escape::write_escaped_html(f, tag);
+                escape::write_escaped_html(f, tag)?;
+                let foreign = render::is_foreign_element(tag, cx.in_foreign_content);
+                attrs.write_html_in(f, cx.syntax, foreign)?;
+
+                if cx.syntax == Syntax::Xml && minify::flatten(children).is_empty() {
+                    f.write_char('/')?;
+                }
                 f.write_char('>')?;
             }
             Element::LeafTag { tag, attrs } => {
                 f.write_char('<')?;
-                escape::write_escaped_html(f, tag);
-
-                if !attrs.0.is_empty() {
-                    f.write_char(' ')?
-                };
-
-                std::fmt::Display::fmt(attrs, f)?;
+                escape::write_escaped_html(f, tag)?;
+                let foreign = render::is_foreign_element(tag, cx.in_foreign_content);
+                attrs.write_html_in(f, cx.syntax, foreign)?;
 
-                f.write_char('/')?;
+                if cx.syntax != Syntax::Minified {
+                    f.write_char('/')?;
+                }
                 f.write_char('>')?;
             }
-            Element::Fragment { children } => {
-                Self::write_children_html(f, children)?;
+            Element::Fragment { children: _ } => {}
+            Element::Text { text } => Self::write_text(f, text, cx)?,
+            Element::Raw { html } => {
+                f.write_str(html)?;
             }
-            Element::Text { text } => {
-                escape::write_escaped_html(f, text);
-            }
-            Element::Document { children } => {
-                f.write_str("<!doctype html>")?;
-                Self::write_children_html(f, children)?;
+            Element::CData { text } => match cx.syntax {
+                Syntax::Xml => escape::write_cdata(f, text)?,
+                _ => Self::write_text(f, text, cx)?,
+            },
+            Element::ProcessingInstruction { target, data } => {
+                f.write_str("<?")?;
+                f.write_str(target)?;
+                if !data.is_empty() {
+                    f.write_char(' ')?;
+                    f.write_str(data)?;
+                }
+                f.write_str("?>")?;
             }
+            Element::Document { children: _ } => match cx.xml_options {
+                Some(options) => options.write_prolog(f)?,
+                None => f.write_str("<!doctype html>")?,
+            },
             Element::Nothing => {}
         };
 
         Ok(())
     }
 
-    fn write_children_html(
-        f: &mut std::fmt::Formatter<'_>,
+    fn write_text<W>(f: &mut W, text: &str, cx: Context<'_>) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        match (cx.syntax, cx.text_mode) {
+            // XML has no raw text elements, so the contents of `script` are escaped as well.
+            (Syntax::Xml, _) => escape::write_escaped_xml(f, text),
+            (_, TextMode::Escaped) => escape::write_escaped_html(f, text),
+            (_, TextMode::Raw { tag }) => escape::write_raw_text(f, text, tag),
+        }
+    }
+
+    /// Write everything that comes after this element's children.
+    pub(crate) fn write_closing_html<W>(&self, f: &mut W, cx: Context<'_>) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        if let Element::Tag { tag, children, .. } = self {
+            if cx.syntax == Syntax::Minified
+                && minify::can_omit_end_tag(tag, cx.next_sibling, cx.parent)
+            {
+                return Ok(());
+            }
+            if cx.syntax == Syntax::Xml && minify::flatten(children).is_empty() {
+                return Ok(());
+            }
+
+            f.write_char('<')?;
+            f.write_char('/')?;
+            escape::write_escaped_html(f, tag)?;
+            f.write_char('>')?;
+        }
+
+        Ok(())
+    }
+
+    fn write_children_html<W>(
+        f: &mut W,
         children_with_empty: &[Element],
-    ) -> std::fmt::Result {
+        cx: Context<'_>,
+    ) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        if cx.syntax == Syntax::Minified {
+            // Whether a tag can be omitted depends on the element that follows it,
+            // so look through fragments to find the actual siblings.
+            let children = minify::flatten(children_with_empty);
+            for (i, child) in children.iter().enumerate() {
+                let cx = Context {
+                    next_sibling: children.get(i + 1).copied(),
+                    ..cx
+                };
+                child.write_html_in(f, cx)?;
+            }
+
+            return Ok(());
+        }
+
         let children = children_with_empty
             .iter()
             .filter(|c| !matches!(c, Element::Nothing));
 
         for child in children {
-            child.write_html(f)?;
+            child.write_html_in(f, cx)?;
         }
 
         Ok(())
@@ -149,6 +410,9 @@ impl Element {
                 attrs: _,
             } => Some(children),
             Element::Text { text: _ } => None,
+            Element::Raw { html: _ } => None,
+            Element::CData { .. } => None,
+            Element::ProcessingInstruction { .. } => None,
             Element::Fragment { children } => Some(children),
             Element::Document { children } => Some(children),
             Element::Nothing => None,
@@ -156,18 +420,21 @@ impl Element {
         }
     }
 
-    pub(crate) fn attrs_mut(&mut self) -> Option<&mut Vec<Attr>> {
+    pub(crate) fn attrs_mut(&mut self) -> Option<&mut Attrs> {
         match self {
             Element::Tag {
                 children: _,
                 tag: _,
                 attrs,
-            } => Some(&mut attrs.0),
+            } => Some(attrs),
             Element::Text { text: _ } => None,
+            Element::Raw { html: _ } => None,
+            Element::CData { .. } => None,
+            Element::ProcessingInstruction { .. } => None,
             Element::Fragment { children: _ } => None,
             Element::Document { children: _ } => None,
             Element::Nothing => None,
-            Element::LeafTag { attrs, .. } => Some(&mut attrs.0),
+            Element::LeafTag { attrs, .. } => Some(attrs),
         }
     }
 }
@@ -178,6 +445,36 @@ impl std::fmt::Display for Element {
     }
 }
 
+impl std::fmt::Display for VoidElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char('<')?;
+        escape::write_escaped_html(f, &self.tag)?;
+        self.attrs.write_html_in(f, Syntax::Html, false)?;
+        f.write_str("/>")
+    }
+}
+
+impl std::fmt::Display for Text {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        escape::write_escaped_html(f, &self.0)
+    }
+}
+
+impl From<VoidElement> for Element {
+    fn from(value: VoidElement) -> Self {
+        Element::LeafTag {
+            tag: value.tag,
+            attrs: value.attrs,
+        }
+    }
+}
+
+impl From<Text> for Element {
+    fn from(value: Text) -> Self {
+        Element::Text { text: value.0 }
+    }
+}
+
 impl From<()> for Element {
     fn from(_value: ()) -> Self {
         Element::Nothing
@@ -186,19 +483,25 @@ impl From<()> for Element {
 
 impl From<String> for Element {
     fn from(value: String) -> Self {
-        declare::text(value)
+        declare::text(value).into()
     }
 }
 
 impl From<&str> for Element {
     fn from(value: &str) -> Self {
-        declare::text(value)
+        declare::text(value).into()
     }
 }
 
 impl From<&String> for Element {
     fn from(value: &String) -> Self {
-        declare::text(value)
+        declare::text(value).into()
+    }
+}
+
+impl From<TrustedHtml> for Element {
+    fn from(value: TrustedHtml) -> Self {
+        Element::Raw { html: value.0 }
     }
 }
 
@@ -222,7 +525,7 @@ impl From<Option<Element>> for Element {
 
 #[cfg(test)]
 mod tests {
-    use crate::prelude::*;
+    use crate::{attr::Attrs, prelude::*};
 
     #[test]
     fn nothing_element() {
@@ -230,4 +533,146 @@ mod tests {
         let doc = body([]).with(nothing());
         assert_eq!(doc.to_html(), body([]).to_html());
     }
+
+    #[test]
+    fn void_elements_and_text() {
+        let field = input(type_("text")).attr("name", "q");
+        assert_eq!(field.to_html(), r#"<input type="text" name="q"/>"#);
+        assert_eq!(text("a < b").to_html(), "a &lt; b");
+        assert_eq!(text("a < b").as_str(), "a < b");
+
+        let doc = label([]).with((text("Search"), field.clone(), br([])));
+        assert_eq!(
+            doc.to_html(),
+            r#"<label>Search<input type="text" name="q"/><br/></label>"#
+        );
+        assert_eq!(
+            Element::from(field).to_html(),
+            r#"<input type="text" name="q"/>"#
+        );
+    }
+
+    #[test]
+    fn render_into_reused_buffer() {
+        let doc = div(class("a")).with((p([]).with("one & two"), br([])));
+        let mut buf = String::new();
+        doc.render_into(&mut buf);
+        assert_eq!(buf, doc.to_html());
+
+        buf.clear();
+        p([]).render_into(&mut buf);
+        assert_eq!(buf, "<p></p>");
+    }
+
+    #[test]
+    fn write_to_io() {
+        let doc = div(class("a")).with((p([]).with("one & two"), br([])));
+        let mut out = Vec::new();
+        doc.write_to(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), doc.to_html());
+    }
+
+    #[test]
+    fn write_to_propagates_io_errors() {
+        struct Full;
+
+        impl std::io::Write for Full {
+            fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::StorageFull.into())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let err = div([]).with("text").write_to(&mut Full).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::StorageFull);
+    }
+
+    #[test]
+    fn trusted_html_is_not_escaped() {
+        let rendered_markdown = trusted_html_unchecked("<p>Some <em>markdown</em> &amp; more</p>");
+        let doc = article([]).with([
+            Element::from(rendered_markdown.clone()),
+            text("<p>").into(),
+            rendered_markdown.into(),
+        ]);
+        assert_eq!(
+            doc.to_html(),
+            "<article><p>Some <em>markdown</em> &amp; more</p>&lt;p&gt;<p>Some <em>markdown</em> \
+             &amp; more</p></article>"
+        );
+    }
+
+    #[test]
+    fn trusted_html_is_not_flattened() {
+        let doc = div([]).with(trusted_html_unchecked("<b>1</b>"));
+        assert_eq!(
+            doc,
+            Element::Tag {
+                tag: "div".into(),
+                attrs: Attrs::new(vec![]),
+                children: vec![Element::Raw {
+                    html: "<b>1</b>".into()
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn script_contents_are_not_escaped() {
+        let doc = script([]).with("if (a < b && c > d) { log('</p>'); }");
+        assert_eq!(
+            doc.to_html(),
+            "<script>if (a < b && c > d) { log('</p>'); }</script>"
+        );
+
+        let doc = style_tag([]).with(fragment().with("a > b { content: \"&\" }"));
+        assert_eq!(doc.to_html(), "<style>a > b { content: \"&\" }</style>");
+    }
+
+    #[test]
+    fn raw_text_cannot_close_its_element() {
+        let doc = script([]).with("var s = '</SCRIPT><img src=x onerror=alert(1)>';");
+        assert_eq!(
+            doc.to_html(),
+            "<script>var s = '<\\/SCRIPT><img src=x onerror=alert(1)>';</script>"
+        );
+
+        let doc = style_tag([]).with("</style><script>alert(1)</script>");
+        assert_eq!(
+            doc.to_html(),
+            "<style><\\/style><script>alert(1)</script></style>"
+        );
+    }
+
+    #[test]
+    fn escapable_raw_text_is_escaped() {
+        let doc = textarea([]).with("</textarea><b>");
+        assert_eq!(
+            doc.to_html(),
+            "<textarea>&lt;/textarea&gt;&lt;b&gt;</textarea>"
+        );
+
+        let doc = title_tag([]).with("a < b");
+        assert_eq!(doc.to_html(), "<title>a &lt; b</title>");
+    }
+
+    #[test]
+    fn runtime_names() {
+        let field = String::from("user-id");
+        let doc = custom_tag(format!("x-{}", "card"), attr(format!("data-{field}"), 7))
+            .with(custom_leaf_tag("keygen", []));
+        assert_eq!(
+            doc.to_html(),
+            r#"<x-card data-user-id="7"><keygen/></x-card>"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid tag name")]
+    fn invalid_custom_tag() {
+        custom_tag("my widget", []);
+    }
 }
@@ -1,18 +1,122 @@
-pub fn write_escaped_html<W>(writer: &mut W, input: &str)
+pub fn write_escaped_html<W>(writer: &mut W, input: &str) -> std::fmt::Result
 where
-    W: std::fmt::Write,
+    W: std::fmt::Write + ?Sized,
 {
     for char in input.chars() {
-        #[allow(unused_must_use)]
         match char {
-            '<' => writer.write_str("&lt;"),
-            '>' => writer.write_str("&gt;"),
-            '&' => writer.write_str("&amp;"),
-            '"' => writer.write_str("&quot;"),
-            '\'' => writer.write_str("&#x27;"),
-            _ => writer.write_char(char),
+            '<' => writer.write_str("&lt;")?,
+            '>' => writer.write_str("&gt;")?,
+            '&' => writer.write_str("&amp;")?,
+            '"' => writer.write_str("&quot;")?,
+            '\'' => writer.write_str("&#x27;")?,
+            _ => writer.write_char(char)?,
         };
     }
+
+    Ok(())
+}
+
+/// Write `input` as XML text.
+///
+/// Carriage returns are written as character references so XML parsers don't turn them
+/// into line feeds, and characters that XML 1.0 doesn't allow at all,
+/// like most control characters, are replaced with U+FFFD.
+pub fn write_escaped_xml<W>(writer: &mut W, input: &str) -> std::fmt::Result
+where
+    W: std::fmt::Write + ?Sized,
+{
+    write_xml(writer, input, false)
+}
+
+/// Write `input` as an XML attribute value, like [`write_escaped_xml`].
+///
+/// Tabs and line feeds are written as character references too,
+/// since XML parsers replace them with spaces in attribute values.
+pub fn write_escaped_xml_attribute<W>(writer: &mut W, input: &str) -> std::fmt::Result
+where
+    W: std::fmt::Write + ?Sized,
+{
+    write_xml(writer, input, true)
+}
+
+fn write_xml<W>(writer: &mut W, input: &str, attribute: bool) -> std::fmt::Result
+where
+    W: std::fmt::Write + ?Sized,
+{
+    for char in input.chars() {
+        match char {
+            '<' => writer.write_str("&lt;")?,
+            '>' => writer.write_str("&gt;")?,
+            '&' => writer.write_str("&amp;")?,
+            '"' => writer.write_str("&quot;")?,
+            '\'' => writer.write_str("&apos;")?,
+            '\r' => writer.write_str("&#xD;")?,
+            '\t' if attribute => writer.write_str("&#x9;")?,
+            '\n' if attribute => writer.write_str("&#xA;")?,
+            _ if !is_xml_char(char) => writer.write_char(char::REPLACEMENT_CHARACTER)?,
+            _ => writer.write_char(char)?,
+        };
+    }
+
+    Ok(())
+}
+
+/// Write `input` as an XML CDATA section.
+///
+/// A `]]>` in `input` would end the section early, so it is split across two sections.
+/// Characters that XML 1.0 doesn't allow are replaced with U+FFFD.
+pub fn write_cdata<W>(writer: &mut W, input: &str) -> std::fmt::Result
+where
+    W: std::fmt::Write + ?Sized,
+{
+    writer.write_str("<![CDATA[")?;
+    for (i, part) in input.split("]]>").enumerate() {
+        if i > 0 {
+            writer.write_str("]]]]><![CDATA[>")?;
+        }
+        for char in part.chars() {
+            match is_xml_char(char) {
+                true => writer.write_char(char)?,
+                false => writer.write_char(char::REPLACEMENT_CHARACTER)?,
+            }
+        }
+    }
+    writer.write_str("]]>")
+}
+
+/// Whether `c` is allowed in XML 1.0 documents.
+fn is_xml_char(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | '\u{20}'..='\u{FFFD}' | '\u{10000}'..='\u{10FFFF}')
+}
+
+/// Write the contents of a raw text element such as `script` or `style`.
+///
+/// Raw text is not decoded by browsers, so entities can't be used here.
+/// Instead, any `</tag` that would close the element early is neutralized by writing `<\/tag`,
+/// and so is `<!--`, which changes how browsers parse the rest of a `script`.
+/// Both rewrites keep the meaning of string literals in JavaScript and CSS.
+pub fn write_raw_text<W>(writer: &mut W, input: &str, tag: &str) -> std::fmt::Result
+where
+    W: std::fmt::Write + ?Sized,
+{
+    let mut rest = input;
+    while let Some(index) = rest.find('<') {
+        writer.write_str(&rest[..index])?;
+        rest = &rest[index + 1..];
+
+        let closes_element = rest.strip_prefix('/').is_some_and(|after_slash| {
+            after_slash
+                .get(..tag.len())
+                .is_some_and(|name| name.eq_ignore_ascii_case(tag))
+        });
+        if closes_element || rest.starts_with("!--") {
+            writer.write_str("<\\")?;
+        } else {
+            writer.write_char('<')?;
+        }
+    }
+
+    writer.write_str(rest)
 }
 
 #[cfg(test)]
@@ -21,7 +125,7 @@ mod tests {
 
     fn escape_string(input: &str) -> String {
         let mut res = String::new();
-        write_escaped_html(&mut res, input);
+        write_escaped_html(&mut res, input).unwrap();
         res
     }
 
@@ -31,6 +135,28 @@ mod tests {
         assert_eq!("&lt;&gt;&amp;&quot;&#x27;", escaped);
     }
 
+    #[test]
+    fn raw_text() {
+        let mut res = String::new();
+        write_raw_text(&mut res, "a<b </p> </Script> <!-- </scrip", "script").unwrap();
+        assert_eq!(r"a<b </p> <\/Script> <\!-- </scrip", res);
+    }
+
+    #[test]
+    fn xml() {
+        let mut res = String::new();
+        write_escaped_xml(&mut res, "<a href='x'>\r\n\t\u{1}&").unwrap();
+        assert_eq!("&lt;a href=&apos;x&apos;&gt;&#xD;\n\t\u{FFFD}&amp;", res);
+
+        res.clear();
+        write_escaped_xml_attribute(&mut res, "a\tb\nc\"").unwrap();
+        assert_eq!("a&#x9;b&#xA;c&quot;", res);
+
+        res.clear();
+        write_cdata(&mut res, "if (a]]>b && c < d)").unwrap();
+        assert_eq!("<![CDATA[if (a]]]]><![CDATA[>b && c < d)]]>", res);
+    }
+
     #[test]
     fn no_changes_to_other_text() {
         let input =
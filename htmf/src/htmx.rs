@@ -0,0 +1,681 @@
+//! Attributes for [htmx](https://htmx.org).
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use htmf::htmx::*;
+//! use htmf::prelude::*;
+//!
+//! let search = input([
+//!     name("q"),
+//!     hx_get("/search"),
+//!     hx_trigger(Trigger::event("input").changed().delay(Duration::from_millis(500))),
+//!     hx_target(Target::Closest("form".into())),
+//!     hx_swap(SwapSpec::new(Swap::OuterHtml).settle(Duration::from_secs(1))),
+//! ]);
+//! assert_eq!(
+//!     search.to_html(),
+//!     r#"<input name="q" hx-get="/search" hx-trigger="input changed delay:500ms" hx-target="closest form" hx-swap="outerHTML settle:1s"/>"#
+//! );
+//! ```
+
+use std::{borrow::Cow, fmt, time::Duration};
+
+use serde::Serialize;
+
+use crate::{
+    attr::{self, Attr, AttrValue, Attrs, IntoAttrValue},
+    json,
+    url::IntoUrl,
+};
+
+macro_rules! define_hx_function {
+    ($name:ident, $key:literal, url) => {
+        /// The value is sanitized according to the active [`UrlPolicy`](crate::url::UrlPolicy).
+        pub fn $name<E, C, M>(value: C) -> Attrs<E>
+        where
+            C: IntoUrl<M>,
+        {
+            Attr(Cow::Borrowed($key), value.into_url()).into()
+        }
+    };
+    ($name:ident, $key:literal, flag) => {
+        /// Rendered without a value if `value` is `true`, and left out otherwise.
+        pub fn $name<E>(value: bool) -> Attrs<E> {
+            Attr(Cow::Borrowed($key), value.into()).into()
+        }
+    };
+    ($name:ident, $key:literal, $type:ty) => {
+        pub fn $name<E, C>(value: C) -> Attrs<E>
+        where
+            C: Into<$type>,
+        {
+            let value: $type = value.into();
+            Attr(Cow::Borrowed($key), AttrValue::String(value.to_string())).into()
+        }
+    };
+    ($name:ident, $key:literal) => {
+        pub fn $name<E, C, M>(value: C) -> Attrs<E>
+        where
+            C: IntoAttrValue<M>,
+        {
+            Attr(Cow::Borrowed($key), value.into_attr_value()).into()
+        }
+    };
+}
+
+define_hx_function!(hx_get, "hx-get", url);
+define_hx_function!(hx_post, "hx-post", url);
+define_hx_function!(hx_put, "hx-put", url);
+define_hx_function!(hx_patch, "hx-patch", url);
+define_hx_function!(hx_delete, "hx-delete", url);
+
+define_hx_function!(hx_boost, "hx-boost", bool);
+define_hx_function!(hx_confirm, "hx-confirm");
+define_hx_function!(hx_disable, "hx-disable", flag);
+define_hx_function!(hx_disabled_elt, "hx-disabled-elt", Target);
+define_hx_function!(hx_disinherit, "hx-disinherit");
+define_hx_function!(hx_encoding, "hx-encoding");
+define_hx_function!(hx_ext, "hx-ext");
+define_hx_function!(hx_history, "hx-history", bool);
+define_hx_function!(hx_history_elt, "hx-history-elt", flag);
+define_hx_function!(hx_include, "hx-include", Target);
+define_hx_function!(hx_indicator, "hx-indicator", Target);
+define_hx_function!(hx_inherit, "hx-inherit");
+define_hx_function!(hx_params, "hx-params");
+define_hx_function!(hx_preserve, "hx-preserve", flag);
+define_hx_function!(hx_prompt, "hx-prompt");
+define_hx_function!(hx_push_url, "hx-push-url");
+define_hx_function!(hx_replace_url, "hx-replace-url");
+define_hx_function!(hx_request, "hx-request");
+define_hx_function!(hx_select, "hx-select");
+define_hx_function!(hx_select_oob, "hx-select-oob");
+define_hx_function!(hx_swap, "hx-swap", SwapSpec);
+define_hx_function!(hx_swap_oob, "hx-swap-oob");
+define_hx_function!(hx_sync, "hx-sync");
+define_hx_function!(hx_target, "hx-target", Target);
+define_hx_function!(hx_trigger, "hx-trigger", Triggers);
+define_hx_function!(hx_validate, "hx-validate", bool);
+
+/// `hx-vals`, serialized as JSON.
+///
+/// # Panics
+///
+/// If `value` can't be serialized as JSON. Use [`try_hx_vals`] to handle the error instead.
+pub fn hx_vals<E, T>(value: &T) -> Attrs<E>
+where
+    T: Serialize + ?Sized,
+{
+    json::json_attr(Cow::Borrowed("hx-vals"), value)
+}
+
+/// `hx-vals`, serialized as JSON, or the error if `value` can't be serialized.
+pub fn try_hx_vals<E, T>(value: &T) -> Result<Attrs<E>, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    json::try_json_attr(Cow::Borrowed("hx-vals"), value)
+}
+
+/// `hx-headers`, serialized as JSON.
+///
+/// # Panics
+///
+/// If `value` can't be serialized as JSON. Use [`try_hx_headers`] to handle the error instead.
+pub fn hx_headers<E, T>(value: &T) -> Attrs<E>
+where
+    T: Serialize + ?Sized,
+{
+    json::json_attr(Cow::Borrowed("hx-headers"), value)
+}
+
+/// `hx-headers`, serialized as JSON, or the error if `value` can't be serialized.
+pub fn try_hx_headers<E, T>(value: &T) -> Result<Attrs<E>, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    json::try_json_attr(Cow::Borrowed("hx-headers"), value)
+}
+
+/// `hx-on:event`, which runs `script` when `event` is triggered.
+/// For htmx events, write `::after-request` or `htmx:after-request` as the event.
+///
+/// # Panics
+///
+/// If the resulting attribute name is not valid, or the active
+/// [`AttrPolicy`](crate::attr::AttrPolicy) forbids event handlers.
+pub fn hx_on<E, C, M>(event: &str, script: C) -> Attrs<E>
+where
+    C: IntoAttrValue<M>,
+{
+    let name = attr::checked_name(Cow::Owned(format!("hx-on:{event}")));
+    Attr(name, script.into_attr_value()).into()
+}
+
+/// Writes a duration the way htmx parses it, like `500ms` or `1s`.
+/// htmx only supports whole milliseconds, so fractions are rounded up.
+struct DurationDisplay(Duration);
+
+impl fmt::Display for DurationDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let millis = self.0.as_nanos().div_ceil(1_000_000);
+        if millis.is_multiple_of(1000) {
+            write!(f, "{}s", millis / 1000)
+        } else {
+            write!(f, "{millis}ms")
+        }
+    }
+}
+
+/// How the response is swapped into the target, used by [`hx_swap`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Swap {
+    InnerHtml,
+    OuterHtml,
+    TextContent,
+    BeforeBegin,
+    AfterBegin,
+    BeforeEnd,
+    AfterEnd,
+    Delete,
+    None,
+}
+
+impl fmt::Display for Swap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Swap::InnerHtml => "innerHTML",
+            Swap::OuterHtml => "outerHTML",
+            Swap::TextContent => "textContent",
+            Swap::BeforeBegin => "beforebegin",
+            Swap::AfterBegin => "afterbegin",
+            Swap::BeforeEnd => "beforeend",
+            Swap::AfterEnd => "afterend",
+            Swap::Delete => "delete",
+            Swap::None => "none",
+        })
+    }
+}
+
+/// Where to scroll or which element to show after swapping.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Position {
+    Top,
+    Bottom,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Position::Top => "top",
+            Position::Bottom => "bottom",
+        })
+    }
+}
+
+/// A [`Swap`] style with modifiers.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SwapSpec {
+    swap: Swap,
+    modifiers: Vec<SwapModifier>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+enum SwapModifier {
+    Swap(Duration),
+    Settle(Duration),
+    Transition(bool),
+    IgnoreTitle(bool),
+    FocusScroll(bool),
+    Scroll(Option<Target>, Position),
+    Show(Option<Target>, Position),
+    ShowNone,
+}
+
+impl SwapSpec {
+    pub fn new(swap: Swap) -> SwapSpec {
+        SwapSpec {
+            swap,
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Wait `delay` before swapping in the new content.
+    pub fn swap(self, delay: Duration) -> SwapSpec {
+        self.modifier(SwapModifier::Swap(delay))
+    }
+
+    /// Wait `delay` between swapping and settling.
+    pub fn settle(self, delay: Duration) -> SwapSpec {
+        self.modifier(SwapModifier::Settle(delay))
+    }
+
+    /// Use the View Transitions API.
+    pub fn transition(self, value: bool) -> SwapSpec {
+        self.modifier(SwapModifier::Transition(value))
+    }
+
+    /// Keep the page title, even if the response contains a `<title>`.
+    pub fn ignore_title(self, value: bool) -> SwapSpec {
+        self.modifier(SwapModifier::IgnoreTitle(value))
+    }
+
+    pub fn focus_scroll(self, value: bool) -> SwapSpec {
+        self.modifier(SwapModifier::FocusScroll(value))
+    }
+
+    /// Scroll the target to `position`.
+    pub fn scroll(self, position: Position) -> SwapSpec {
+        self.modifier(SwapModifier::Scroll(None, position))
+    }
+
+    /// Scroll the element matching `target` to `position`.
+    ///
+    /// # Panics
+    ///
+    /// If `target` is neither [`Target::Window`] nor a [`Target::Selector`] without whitespace.
+    /// htmx splits `hx-swap` at whitespace, so it can't parse other targets here.
+    pub fn scroll_in<T: Into<Target>>(self, target: T, position: Position) -> SwapSpec {
+        let target = scroll_target(target.into());
+        self.modifier(SwapModifier::Scroll(Some(target), position))
+    }
+
+    /// Scroll `position` of the target into view.
+    pub fn show(self, position: Position) -> SwapSpec {
+        self.modifier(SwapModifier::Show(None, position))
+    }
+
+    /// Scroll `position` of the element matching `target` into view.
+    ///
+    /// # Panics
+    ///
+    /// If `target` is neither [`Target::Window`] nor a [`Target::Selector`] without whitespace,
+    /// like for [`SwapSpec::scroll_in`].
+    pub fn show_in<T: Into<Target>>(self, target: T, position: Position) -> SwapSpec {
+        let target = scroll_target(target.into());
+        self.modifier(SwapModifier::Show(Some(target), position))
+    }
+
+    /// Don't scroll anything into view.
+    pub fn show_none(self) -> SwapSpec {
+        self.modifier(SwapModifier::ShowNone)
+    }
+
+    fn modifier(mut self, modifier: SwapModifier) -> SwapSpec {
+        self.modifiers.push(modifier);
+        self
+    }
+}
+
+/// Check that htmx can parse `target` in the `scroll:` and `show:` modifiers.
+fn scroll_target(target: Target) -> Target {
+    match &target {
+        Target::Window => target,
+        Target::Selector(selector)
+            if !selector.is_empty() && !selector.contains(char::is_whitespace) =>
+        {
+            target
+        }
+        _ => panic!(
+            "the scroll target {:?} of `hx-swap` should be `window` or a selector without whitespace",
+            target.to_string()
+        ),
+    }
+}
+
+impl From<Swap> for SwapSpec {
+    fn from(swap: Swap) -> Self {
+        SwapSpec::new(swap)
+    }
+}
+
+impl fmt::Display for SwapSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.swap)?;
+        for modifier in &self.modifiers {
+            f.write_str(" ")?;
+            match modifier {
+                SwapModifier::Swap(delay) => write!(f, "swap:{}", DurationDisplay(*delay))?,
+                SwapModifier::Settle(delay) => write!(f, "settle:{}", DurationDisplay(*delay))?,
+                SwapModifier::Transition(value) => write!(f, "transition:{value}")?,
+                SwapModifier::IgnoreTitle(value) => write!(f, "ignoreTitle:{value}")?,
+                SwapModifier::FocusScroll(value) => write!(f, "focus-scroll:{value}")?,
+                SwapModifier::Scroll(None, position) => write!(f, "scroll:{position}")?,
+                SwapModifier::Scroll(Some(target), position) => {
+                    write!(f, "scroll:{target}:{position}")?
+                }
+                SwapModifier::Show(None, position) => write!(f, "show:{position}")?,
+                SwapModifier::Show(Some(target), position) => {
+                    write!(f, "show:{target}:{position}")?
+                }
+                SwapModifier::ShowNone => f.write_str("show:none")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An element selector using htmx's extended CSS syntax, used by [`hx_target`] and others.
+///
+/// Strings convert into [`Target::Selector`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Target {
+    /// The element the attribute is on.
+    This,
+    /// The first element in the document matching a CSS selector.
+    Selector(String),
+    /// The closest ancestor matching a CSS selector, including the element itself.
+    Closest(String),
+    /// The first descendant matching a CSS selector.
+    Find(String),
+    /// The next sibling element, or the next element matching a CSS selector.
+    Next(Option<String>),
+    /// The previous sibling element, or the previous element matching a CSS selector.
+    Previous(Option<String>),
+    Document,
+    Window,
+}
+
+impl From<&str> for Target {
+    fn from(selector: &str) -> Self {
+        Target::Selector(selector.to_string())
+    }
+}
+
+impl From<String> for Target {
+    fn from(selector: String) -> Self {
+        Target::Selector(selector)
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::This => f.write_str("this"),
+            Target::Selector(selector) => f.write_str(selector),
+            Target::Closest(selector) => write!(f, "closest {selector}"),
+            Target::Find(selector) => write!(f, "find {selector}"),
+            Target::Next(None) => f.write_str("next"),
+            Target::Next(Some(selector)) => write!(f, "next {selector}"),
+            Target::Previous(None) => f.write_str("previous"),
+            Target::Previous(Some(selector)) => write!(f, "previous {selector}"),
+            Target::Document => f.write_str("document"),
+            Target::Window => f.write_str("window"),
+        }
+    }
+}
+
+/// Which queued events to keep while a request is in flight, used by [`Trigger::queue`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Queue {
+    First,
+    Last,
+    All,
+    None,
+}
+
+impl fmt::Display for Queue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Queue::First => "first",
+            Queue::Last => "last",
+            Queue::All => "all",
+            Queue::None => "none",
+        })
+    }
+}
+
+/// An event that triggers a request, used by [`hx_trigger`].
+///
+/// Strings convert into a trigger for the event of that name.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Trigger {
+    event: Cow<'static, str>,
+    filter: Option<String>,
+    modifiers: Vec<TriggerModifier>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+enum TriggerModifier {
+    Once,
+    Changed,
+    Delay(Duration),
+    Throttle(Duration),
+    From(Target),
+    Target(String),
+    Consume,
+    Queue(Queue),
+}
+
+impl Trigger {
+    /// Trigger on the DOM event `name`, like `click`.
+    pub fn event<N>(name: N) -> Trigger
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        Trigger {
+            event: name.into(),
+            filter: None,
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Trigger when the element is loaded.
+    pub fn load() -> Trigger {
+        Trigger::event("load")
+    }
+
+    /// Trigger when the element is scrolled into the viewport.
+    pub fn revealed() -> Trigger {
+        Trigger::event("revealed")
+    }
+
+    /// Trigger when the element intersects the viewport.
+    pub fn intersect() -> Trigger {
+        Trigger::event("intersect")
+    }
+
+    /// Poll every `interval`.
+    pub fn every(interval: Duration) -> Trigger {
+        Trigger::event(format!("every {}", DurationDisplay(interval)))
+    }
+
+    /// Only trigger if the JavaScript expression `filter` is true, like `ctrlKey`.
+    pub fn filter<F: Into<String>>(mut self, filter: F) -> Trigger {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    pub fn once(self) -> Trigger {
+        self.modifier(TriggerModifier::Once)
+    }
+
+    /// Only trigger if the value of the element changed.
+    pub fn changed(self) -> Trigger {
+        self.modifier(TriggerModifier::Changed)
+    }
+
+    /// Wait for `delay` without further events before triggering.
+    pub fn delay(self, delay: Duration) -> Trigger {
+        self.modifier(TriggerModifier::Delay(delay))
+    }
+
+    /// Ignore further events for `interval` after triggering.
+    pub fn throttle(self, interval: Duration) -> Trigger {
+        self.modifier(TriggerModifier::Throttle(interval))
+    }
+
+    /// Listen for the event on another element.
+    pub fn from<T: Into<Target>>(self, target: T) -> Trigger {
+        self.modifier(TriggerModifier::From(target.into()))
+    }
+
+    /// Only trigger if the event's target matches the CSS selector `selector`.
+    pub fn target<S: Into<String>>(self, selector: S) -> Trigger {
+        self.modifier(TriggerModifier::Target(selector.into()))
+    }
+
+    /// Don't let the event trigger requests on parent elements.
+    pub fn consume(self) -> Trigger {
+        self.modifier(TriggerModifier::Consume)
+    }
+
+    pub fn queue(self, queue: Queue) -> Trigger {
+        self.modifier(TriggerModifier::Queue(queue))
+    }
+
+    fn modifier(mut self, modifier: TriggerModifier) -> Trigger {
+        self.modifiers.push(modifier);
+        self
+    }
+}
+
+impl From<&'static str> for Trigger {
+    fn from(event: &'static str) -> Self {
+        Trigger::event(event)
+    }
+}
+
+impl From<String> for Trigger {
+    fn from(event: String) -> Self {
+        Trigger::event(event)
+    }
+}
+
+impl fmt::Display for Trigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.event)?;
+        if let Some(filter) = &self.filter {
+            write!(f, "[{filter}]")?;
+        }
+        for modifier in &self.modifiers {
+            match modifier {
+                TriggerModifier::Once => f.write_str(" once")?,
+                TriggerModifier::Changed => f.write_str(" changed")?,
+                TriggerModifier::Delay(delay) => write!(f, " delay:{}", DurationDisplay(*delay))?,
+                TriggerModifier::Throttle(interval) => {
+                    write!(f, " throttle:{}", DurationDisplay(*interval))?
+                }
+                TriggerModifier::From(target) => write!(f, " from:{target}")?,
+                TriggerModifier::Target(selector) => write!(f, " target:{selector}")?,
+                TriggerModifier::Consume => f.write_str(" consume")?,
+                TriggerModifier::Queue(queue) => write!(f, " queue:{queue}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One or more [`Trigger`]s, separated by commas.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Triggers(Vec<Trigger>);
+
+impl<T> From<T> for Triggers
+where
+    T: Into<Trigger>,
+{
+    fn from(trigger: T) -> Self {
+        Triggers(vec![trigger.into()])
+    }
+}
+
+impl<const N: usize> From<[Trigger; N]> for Triggers {
+    fn from(triggers: [Trigger; N]) -> Self {
+        Triggers(triggers.into())
+    }
+}
+
+impl From<Vec<Trigger>> for Triggers {
+    fn from(triggers: Vec<Trigger>) -> Self {
+        Triggers(triggers)
+    }
+}
+
+impl fmt::Display for Triggers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, trigger) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{trigger}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn swap() {
+        let spec = SwapSpec::new(Swap::BeforeEnd)
+            .swap(Duration::from_millis(100))
+            .transition(true)
+            .scroll(Position::Bottom)
+            .show_in("#list", Position::Top);
+        assert_eq!(
+            spec.to_string(),
+            "beforeend swap:100ms transition:true scroll:bottom show:#list:top"
+        );
+        assert_eq!(SwapSpec::from(Swap::InnerHtml).to_string(), "innerHTML");
+        assert_eq!(
+            SwapSpec::new(Swap::OuterHtml)
+                .swap(Duration::from_micros(200))
+                .settle(Duration::from_micros(1500))
+                .scroll_in(Target::Window, Position::Top)
+                .to_string(),
+            "outerHTML swap:1ms settle:2ms scroll:window:top"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "should be `window` or a selector without whitespace")]
+    fn swap_targets_with_whitespace() {
+        SwapSpec::new(Swap::InnerHtml).show_in(Target::Closest(".list".into()), Position::Top);
+    }
+
+    #[test]
+    fn triggers() {
+        let triggers = Triggers::from([
+            Trigger::event("click")
+                .filter("ctrlKey")
+                .once()
+                .from("body"),
+            Trigger::every(Duration::from_secs(2)),
+            Trigger::event("keyup")
+                .throttle(Duration::from_millis(250))
+                .queue(Queue::Last)
+                .target("#search")
+                .consume(),
+        ]);
+        assert_eq!(
+            triggers.to_string(),
+            "click[ctrlKey] once from:body, every 2s, keyup throttle:250ms queue:last \
+             target:#search consume"
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        let vals = serde_json::json!({ "id": 1, "name": "a\"b" });
+        let element = button([
+            hx_post("javascript:alert(1)"),
+            hx_target(Target::Next(None)),
+            hx_trigger("click"),
+            hx_boost(true),
+            hx_disable(true),
+            hx_vals(&vals),
+            hx_on("::after-request", "this.reset()"),
+        ]);
+        assert_eq!(
+            element.to_html(),
+            r#"<button hx-post="about:invalid#htmf-unsafe-url" hx-target="next" hx-trigger="click" hx-boost="true" hx-disable hx-vals="{&quot;id&quot;:1,&quot;name&quot;:&quot;a\&quot;b&quot;}" hx-on:::after-request="this.reset()"></button>"#
+        );
+    }
+}
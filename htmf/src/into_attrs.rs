@@ -1,41 +1,45 @@
-use crate::attr::{Attr, Attrs};
+use crate::{
+    attr::{Attr, Attrs},
+    spec::AnyElement,
+};
 
-pub trait IntoAttrs {
+/// Values accepted as attributes by the tag functions of elements of type `E`.
+pub trait IntoAttrs<E = AnyElement> {
     fn into_attrs(self) -> Attrs;
 }
 
-impl IntoAttrs for Attr {
+impl<E> IntoAttrs<E> for Attr {
     fn into_attrs(self) -> Attrs {
-        Attrs(vec![self])
+        Attrs::new(vec![self])
     }
 }
 
-impl IntoAttrs for () {
+impl<E> IntoAttrs<E> for () {
     fn into_attrs(self) -> Attrs {
-        Attrs(Vec::new())
+        Attrs::new(Vec::new())
     }
 }
 
-impl IntoAttrs for Attrs {
+impl<E> IntoAttrs<E> for Attrs<E> {
     fn into_attrs(self) -> Attrs {
-        self
+        self.cast()
     }
 }
 
-impl<const N: usize> IntoAttrs for [Attrs; N] {
+impl<E, const N: usize> IntoAttrs<E> for [Attrs<E>; N] {
     fn into_attrs(self) -> Attrs {
-        Attrs(self.into_iter().flat_map(|attrs| attrs.0).collect())
+        self.into_iter().flat_map(|attrs| attrs.0).collect()
     }
 }
 
-impl IntoAttrs for Option<Attrs> {
+impl<E> IntoAttrs<E> for Option<Attrs<E>> {
     fn into_attrs(self) -> Attrs {
-        Attrs(self.into_iter().flat_map(|attrs| attrs.0).collect())
+        self.into_iter().flat_map(|attrs| attrs.0).collect()
     }
 }
 
-impl IntoAttrs for Vec<Attrs> {
+impl<E> IntoAttrs<E> for Vec<Attrs<E>> {
     fn into_attrs(self) -> Attrs {
-        Attrs(self.into_iter().flat_map(|attrs| attrs.0).collect())
+        self.into_iter().flat_map(|attrs| attrs.0).collect()
     }
 }
@@ -1,6 +1,16 @@
 use crate::element::Element;
 
 /// Used for arguments that accept an arbitrary, ordered collection of elements.
+///
+/// Tuples allow combining different kinds of elements, like an [`Element`] and a
+/// [`VoidElement`](crate::element::VoidElement), which can't share an array:
+///
+/// ```rust
+/// use htmf::prelude::*;
+///
+/// let field = label([]).with((text("Name"), input(name("name"))));
+/// assert_eq!(field.to_html(), r#"<label>Name<input name="name"/></label>"#);
+/// ```
 pub trait IntoElements {
     fn into_elements(self) -> Vec<Element>;
 }
@@ -27,3 +37,30 @@ where
         self.into_iter().map(Into::into).collect()
     }
 }
+
+macro_rules! impl_into_elements_for_tuple {
+    ($($element:ident),+) => {
+        impl<$($element),+> IntoElements for ($($element,)+)
+        where
+            $($element: Into<Element>,)+
+        {
+            #[allow(non_snake_case)]
+            fn into_elements(self) -> Vec<Element> {
+                let ($($element,)+) = self;
+                vec![$($element.into()),+]
+            }
+        }
+    };
+}
+
+impl_into_elements_for_tuple!(A, B);
+impl_into_elements_for_tuple!(A, B, C);
+impl_into_elements_for_tuple!(A, B, C, D);
+impl_into_elements_for_tuple!(A, B, C, D, E);
+impl_into_elements_for_tuple!(A, B, C, D, E, F);
+impl_into_elements_for_tuple!(A, B, C, D, E, F, G);
+impl_into_elements_for_tuple!(A, B, C, D, E, F, G, H);
+impl_into_elements_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_into_elements_for_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_into_elements_for_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_into_elements_for_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -0,0 +1,71 @@
+//! JSON attribute values, shared by [`data_json`](crate::data::data_json),
+//! [`hx_vals`](crate::htmx::hx_vals) and [`x_data`](crate::alpine::x_data).
+//!
+//! Serialization fails for values that have no JSON representation,
+//! like maps with non-string keys. The `try_` functions return these errors,
+//! and the others panic.
+
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use crate::attr::{Attr, AttrValue, Attrs};
+
+/// The attribute `name` with `value` serialized as JSON.
+pub(crate) fn try_json_attr<E, T>(
+    name: Cow<'static, str>,
+    value: &T,
+) -> Result<Attrs<E>, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    let json = serde_json::to_string(value)?;
+    Ok(Attr(name, AttrValue::String(json)).into())
+}
+
+/// Like [`try_json_attr`].
+///
+/// # Panics
+///
+/// If `value` can't be serialized as JSON.
+pub(crate) fn json_attr<E, T>(name: Cow<'static, str>, value: &T) -> Attrs<E>
+where
+    T: Serialize + ?Sized,
+{
+    match try_json_attr(name.clone(), value) {
+        Ok(attrs) => attrs,
+        Err(error) => panic!("value of {name:?} should serialize to JSON: {error}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, collections::BTreeMap};
+
+    use pretty_assertions::assert_eq;
+
+    use super::{json_attr, try_json_attr};
+    use crate::{attr::Attrs, prelude::*};
+
+    fn tuple_keys() -> BTreeMap<(u8, u8), u8> {
+        BTreeMap::from([((1, 2), 3)])
+    }
+
+    #[test]
+    fn serialization_errors() {
+        let attrs: Result<Attrs, _> = try_json_attr(Cow::Borrowed("x-data"), &tuple_keys());
+        assert!(attrs.is_err());
+
+        let attrs = try_json_attr(Cow::Borrowed("x-data"), &BTreeMap::from([(1, 2)]));
+        assert_eq!(
+            div(attrs.unwrap()).to_html(),
+            r#"<div x-data="{&quot;1&quot;:2}"></div>"#
+        );
+    }
+
+    #[test]
+    #[should_panic = r#"value of "hx-vals" should serialize to JSON"#]
+    fn panics_on_errors() {
+        let _: Attrs = json_attr(Cow::Borrowed("hx-vals"), &tuple_keys());
+    }
+}
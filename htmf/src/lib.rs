@@ -1,16 +1,50 @@
 #![doc = include_str!("../README.md")]
 
+// The `html_elements!`, `html_element_methods!`, `html_attributes!` and `aria_attributes!` macros,
+// generated by `build.rs` from `spec/html.txt`.
+include!(concat!(env!("OUT_DIR"), "/html.rs"));
+// The `svg_elements!` and `svg_attributes!` macros, generated from `spec/svg.txt`.
+include!(concat!(env!("OUT_DIR"), "/svg.rs"));
+// The `mathml_elements!` and `mathml_attributes!` macros, generated from `spec/mathml.txt`.
+include!(concat!(env!("OUT_DIR"), "/mathml.rs"));
+
+pub mod a11y;
+#[cfg(feature = "alpine")]
+pub mod alpine;
+pub mod aria;
 pub mod attr;
 #[cfg(feature = "unstable-builder")]
 pub mod builder;
+pub mod data;
 pub mod declare;
 pub mod declare_inline;
 pub mod element;
 mod escape;
+#[cfg(feature = "htmx")]
+pub mod htmx;
 pub mod into_attrs;
 pub mod into_elements;
+#[cfg(any(feature = "serde", feature = "htmx", feature = "alpine"))]
+mod json;
+pub mod mathml;
+pub mod mathml_inline;
+mod minify;
+mod names;
 pub mod prelude;
 pub mod prelude_inline;
+pub mod pretty;
+mod render;
+pub mod spec;
+#[cfg(feature = "stream")]
+pub mod stream;
+pub mod style;
+pub mod svg;
+pub mod svg_inline;
+#[cfg(feature = "tailwind")]
+mod tailwind;
+pub mod url;
+pub mod validate;
+pub mod xml;
 
 #[cfg(test)]
 mod tests {
@@ -21,7 +55,7 @@ mod tests {
         use declare::*;
 
         let doc = document().with([html(class("w-full h-full")).with([
-            head([]).with([
+            head([]).with((
                 link([rel("stylesheet"), href("/assets/preflight.css")]),
                 link([rel("stylesheet"), href("/assets/railwind.css")]),
                 script(src("/assets/htmx.1.9.9.js")),
@@ -30,11 +64,11 @@ mod tests {
                     name("viewport"),
                     content("width=device-width,initial-scale=1"),
                 ]),
-            ]),
+            )),
             body(class("w-full h-full text-gray-200 bg-neutral-800")).with([
                 nothing(),
                 p([]).with(text("bonjour")),
-                fragment().with([
+                fragment().with((
                     div([]).with(fragment()),
                     label(vec![
                         class("mt-4 text-neutral-400"),
@@ -46,10 +80,10 @@ mod tests {
                         attr("hx-post", "/validate-password"),
                         name("credentials[password]"),
                         class("rounded py-1.5 px-3 mt-2 bg-neutral-900"),
-                        required("true"),
+                        required(true),
                     ]),
                     input([value(10), type_("number")]),
-                ]),
+                )),
             ]),
         ])]);
         let html = doc.clone().to_html();
@@ -67,7 +101,7 @@ mod tests {
             [
                 head(
                     [],
-                    [
+                    (
                         link([rel("stylesheet"), href("/assets/preflight.css")]),
                         link([rel("stylesheet"), href("/assets/railwind.css")]),
                         script(src("/assets/htmx.1.9.9.js"), ()),
@@ -76,14 +110,14 @@ mod tests {
                             name("viewport"),
                             content("width=device-width,initial-scale=1"),
                         ]),
-                    ],
+                    ),
                 ),
                 body(
                     class("w-full h-full text-gray-200 bg-neutral-800"),
                     [
                         nothing(),
                         p([], text("bonjour")),
-                        fragment([
+                        fragment((
                             div([], ()),
                             label(
                                 [
@@ -96,9 +130,9 @@ mod tests {
                                 type_("password"),
                                 name("credentials[password]"),
                                 class("rounded py-1.5 px-3 mt-2 bg-neutral-900"),
-                                required("true"),
+                                required(true),
                             ]),
-                        ]),
+                        )),
                     ],
                 ),
             ],
@@ -106,4 +140,31 @@ mod tests {
         let html = doc.clone().to_html_pretty().unwrap();
         insta::assert_snapshot!(html);
     }
+
+    #[test]
+    fn spec_elements() {
+        use declare::*;
+
+        let table = table([]).with([
+            colgroup([]).with([col(span_attr(2)), col([])]),
+            tbody([]).with(tr([]).with(td(colspan(3)).with((text("a"), wbr([]), text("b"))))),
+        ]);
+        assert_eq!(
+            table.to_html(),
+            r#"<table><colgroup><col span="2"/><col/></colgroup><tbody><tr><td colspan="3">a<wbr/>b</td></tr></tbody></table>"#
+        );
+
+        let image = img([
+            srcset("a.png 1x, b.png 2x"),
+            sizes("50vw"),
+            loading("lazy"),
+            popover("auto"),
+            contenteditable("plaintext-only"),
+            hidden("until-found"),
+        ]);
+        assert_eq!(
+            image.to_html(),
+            r#"<img srcset="a.png 1x, b.png 2x" sizes="50vw" loading="lazy" popover="auto" contenteditable="plaintext-only" hidden="until-found"/>"#
+        );
+    }
 }
@@ -0,0 +1,71 @@
+//! MathML Core elements and attributes, generated from `spec/mathml.txt`.
+//!
+//! ```rust
+//! use htmf::mathml;
+//! use htmf::prelude::*;
+//!
+//! let half_square = mathml::math(mathml::display("block")).with(mathml::mfrac([]).with([
+//!     mathml::msup([]).with([mathml::mi([]).with("x"), mathml::mn([]).with("2")]),
+//!     mathml::mn([]).with("2"),
+//! ]));
+//! assert_eq!(
+//!     half_square.to_html(),
+//!     r#"<math display="block"><mfrac><msup><mi>x</mi><mn>2</mn></msup><mn>2</mn></mfrac></math>"#
+//! );
+//! ```
+//!
+//! The [`math`] element and attributes that MathML shares with HTML, like [`class`] and
+//! [`width`], are re-exported from [`declare`](crate::declare).
+//! Like HTML attributes, MathML attributes are only accepted by the elements they apply to:
+//!
+//! ```rust,compile_fail
+//! use htmf::mathml;
+//!
+//! mathml::mi(mathml::stretchy(true));
+//! ```
+//!
+//! See [`mathml_inline`](crate::mathml_inline) for MathML elements in the style of
+//! [`declare_inline`](crate::declare_inline).
+
+pub(crate) mod all_attrs;
+mod all_tags;
+
+pub use all_attrs::*;
+pub use all_tags::*;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super as mathml;
+    use crate::prelude::*;
+
+    #[test]
+    fn elements_and_attributes() {
+        let table = mathml::mtable([]).with(
+            mathml::mtr([]).with(
+                mathml::mtd([mathml::columnspan(2), rowspan(1)])
+                    .with(mathml::mo([mathml::stretchy(false), mathml::form("prefix")]).with("(")),
+            ),
+        );
+        let annotated = mathml::semantics([]).with([
+            mathml::mi([mathml::mathvariant("normal"), mathml::mathcolor("red")]).with("x"),
+            mathml::annotation_xml(mathml::encoding("application/xhtml+xml"))
+                .with(span([]).with("x")),
+        ]);
+        let element = mathml::math([]).with([table, annotated]);
+        assert_eq!(
+            element.to_html(),
+            r#"<math><mtable><mtr><mtd columnspan="2" rowspan="1"><mo stretchy="false" form="prefix">(</mo></mtd></mtr></mtable><semantics><mi mathvariant="normal" mathcolor="red">x</mi><annotation-xml encoding="application/xhtml+xml"><span>x</span></annotation-xml></semantics></math>"#
+        );
+    }
+
+    #[test]
+    fn shared_with_html() {
+        let space = mathml::mspace([width("1em"), mathml::depth("2px"), class("gap")]);
+        assert_eq!(
+            space.to_html(),
+            r#"<mspace width="1em" depth="2px" class="gap"></mspace>"#
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use std::borrow::Cow;
+
+use crate::{
+    element::Element, into_attrs::IntoAttrs, into_elements::IntoElements, spec::mathml::elements,
+};
+
+macro_rules! define_tag_function {
+    ($tag:ident, html) => {
+        pub use crate::declare_inline::$tag;
+    };
+
+    ($tag:ident, $kind:ident) => {
+        pub fn $tag<Attrs: IntoAttrs<elements::$kind>, Children: IntoElements>(
+            attrs: Attrs,
+            with: Children,
+        ) -> Element {
+            Element::Tag {
+                tag: Cow::Borrowed(stringify!($tag)),
+                attrs: attrs.into_attrs(),
+                children: with.into_elements(),
+            }
+        }
+    };
+
+    ($tag:ident, $kind:ident, $tag_str:literal) => {
+        pub fn $tag<Attrs: IntoAttrs<elements::$kind>, Children: IntoElements>(
+            attrs: Attrs,
+            with: Children,
+        ) -> Element {
+            Element::Tag {
+                tag: Cow::Borrowed($tag_str),
+                attrs: attrs.into_attrs(),
+                children: with.into_elements(),
+            }
+        }
+    };
+}
+
+mathml_elements!(define_tag_function);
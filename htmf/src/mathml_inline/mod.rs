@@ -0,0 +1,14 @@
+//! The MathML elements of [`htmf::mathml`](crate::mathml), taking their children as second
+//! argument like the functions of [`declare_inline`](crate::declare_inline).
+//!
+//! ```rust
+//! use htmf::mathml_inline as mathml;
+//!
+//! let root = mathml::math((), mathml::msqrt((), mathml::mn((), "2")));
+//! assert_eq!(root.to_html(), "<math><msqrt><mn>2</mn></msqrt></math>");
+//! ```
+
+mod all_tags;
+
+pub use crate::mathml::all_attrs::*;
+pub use all_tags::*;
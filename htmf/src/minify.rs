@@ -0,0 +1,332 @@
+//! Rules for [`Element::to_html_minified`], following the
+//! [optional tags](https://html.spec.whatwg.org/multipage/syntax.html#optional-tags)
+//! and [attribute syntax](https://html.spec.whatwg.org/multipage/syntax.html#attributes-2)
+//! sections of the HTML standard.
+
+use crate::{attr::Attrs, element::Element};
+
+/// Elements that end a `p` element when they follow it.
+const CLOSES_P: [&str; 32] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "search",
+    "section",
+    "table",
+    "ul",
+];
+
+/// Whether `name` is an attribute whose presence alone means `true`,
+/// like the `bool` attributes of `spec/html.txt`.
+pub(crate) fn is_boolean_attribute(name: &str) -> bool {
+    macro_rules! boolean_attribute {
+        ($fn:ident, $name:literal, $marker:ident, bool) => {
+            if name == $name {
+                return true;
+            }
+        };
+        ($($rest:tt)*) => {};
+    }
+    html_attributes!(boolean_attribute);
+    false
+}
+
+pub(crate) fn can_unquote_attribute_value(value: &str) -> bool {
+    !value.is_empty()
+        && !value.contains(|c: char| {
+            c.is_ascii_whitespace() || matches!(c, '"' | '\'' | '=' | '<' | '>' | '`')
+        })
+}
+
+/// Collect `children` with fragments expanded and empty nodes removed,
+/// so that every element sees the sibling that actually follows it in the output.
+pub(crate) fn flatten(children: &[Element]) -> Vec<&Element> {
+    fn collect<'a>(children: &'a [Element], out: &mut Vec<&'a Element>) {
+        for child in children {
+            match child {
+                Element::Fragment { children } => collect(children, out),
+                Element::Nothing => {}
+                Element::Text { text } if text.is_empty() => {}
+                _ => out.push(child),
+            }
+        }
+    }
+
+    let mut out = Vec::with_capacity(children.len());
+    collect(children, &mut out);
+    out
+}
+
+pub(crate) fn can_omit_start_tag(tag: &str, attrs: &Attrs, children: &[Element]) -> bool {
+    if attrs.rendered().next().is_some() {
+        return false;
+    }
+
+    let first_child = flatten(children).first().copied();
+    match tag {
+        "html" => !first_child.is_some_and(is_comment_like),
+        "head" => match first_child {
+            None => true,
+            Some(child) => is_tag(child),
+        },
+        "body" => match first_child {
+            None => true,
+            Some(child) => {
+                !starts_with_space_or_unknown(child)
+                    && !is_one_of(
+                        child,
+                        &["meta", "noscript", "link", "script", "style", "template"],
+                    )
+            }
+        },
+        _ => false,
+    }
+}
+
+/// `parent` is `None` for elements at the root of the rendered tree.
+/// These keep end tags that are only optional when nothing follows them,
+/// since the output may be embedded into other markup.
+pub(crate) fn can_omit_end_tag(tag: &str, next: Option<&Element>, parent: Option<&str>) -> bool {
+    let last_in_parent = next.is_none() && parent.is_some();
+    let next_is = |tags: &[&str]| next.is_some_and(|next| is_one_of(next, tags));
+
+    match tag {
+        "html" | "body" => !next.is_some_and(is_comment_like),
+        "head" | "colgroup" | "caption" => !next.is_some_and(starts_with_space_or_unknown),
+        "li" => last_in_parent || next_is(&["li"]),
+        "dt" => next_is(&["dt", "dd"]),
+        "dd" => last_in_parent || next_is(&["dt", "dd"]),
+        "p" => {
+            next_is(&CLOSES_P)
+                || (last_in_parent
+                    && parent.is_some_and(|parent| {
+                        !["a", "audio", "del", "ins", "map", "noscript", "video"].contains(&parent)
+                            && !parent.contains('-')
+                    }))
+        }
+        "rt" | "rp" => last_in_parent || next_is(&["rt", "rp"]),
+        "optgroup" => last_in_parent || next_is(&["optgroup", "hr"]),
+        "option" => last_in_parent || next_is(&["option", "optgroup", "hr"]),
+        "thead" => next_is(&["tbody", "tfoot"]),
+        "tbody" => last_in_parent || next_is(&["tbody", "tfoot"]),
+        "tfoot" => last_in_parent,
+        "tr" => last_in_parent || next_is(&["tr"]),
+        "td" | "th" => last_in_parent || next_is(&["td", "th"]),
+        _ => false,
+    }
+}
+
+fn is_tag(element: &Element) -> bool {
+    matches!(element, Element::Tag { .. } | Element::LeafTag { .. })
+}
+
+fn is_one_of(element: &Element, tags: &[&str]) -> bool {
+    match element {
+        Element::Tag { tag, .. } | Element::LeafTag { tag, .. } => tags.contains(&&**tag),
+        _ => false,
+    }
+}
+
+/// Whether `element` starts with whitespace, or might start with a comment.
+fn starts_with_space_or_unknown(element: &Element) -> bool {
+    match element {
+        Element::Text { text } | Element::CData { text } => {
+            text.starts_with(|c: char| c.is_ascii_whitespace())
+        }
+        _ => is_comment_like(element),
+    }
+}
+
+/// Whether `element` might be parsed as a comment: raw HTML can start with one,
+/// and HTML parsers turn processing instructions into comments.
+fn is_comment_like(element: &Element) -> bool {
+    matches!(
+        element,
+        Element::Raw { .. } | Element::ProcessingInstruction { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::prelude::*;
+
+    /// Describe the DOM that browsers build from `html`, one node per line.
+    fn dom(html: &str, document: bool) -> String {
+        let parsed = if document {
+            scraper::Html::parse_document(html)
+        } else {
+            scraper::Html::parse_fragment(html)
+        };
+        let mut out = String::new();
+        describe(parsed.tree.root(), 0, &mut out);
+        out
+    }
+
+    fn describe(node: ego_tree::NodeRef<scraper::Node>, depth: usize, out: &mut String) {
+        let indent = "  ".repeat(depth);
+        match node.value() {
+            scraper::Node::Element(element) => {
+                let mut attrs: Vec<_> = element
+                    .attrs()
+                    .map(|(name, value)| format!("{name}={value:?}"))
+                    .collect();
+                attrs.sort();
+                out.push_str(&format!("{indent}<{}> {attrs:?}\n", element.name()));
+            }
+            scraper::Node::Text(text) => out.push_str(&format!("{indent}{:?}\n", &**text)),
+            other => out.push_str(&format!("{indent}{other:?}\n")),
+        }
+        for child in node.children() {
+            describe(child, depth + 1, out);
+        }
+    }
+
+    fn assert_same_dom(element: &Element, document: bool) -> String {
+        let minified = element.to_html_minified();
+        assert_eq!(dom(&element.to_html(), document), dom(&minified, document));
+        minified
+    }
+
+    #[test]
+    fn full_document() {
+        let doc = document().with(html([]).with([
+            head([]).with((meta(charset("utf-8")), title_tag([]).with("Minified"))),
+            body([]).with([
+                p([]).with("One"),
+                p([]).with("Two"),
+                ul([]).with([li([]).with("a"), li([]).with("b")]),
+                p([]).with("Three"),
+            ]),
+        ]));
+        let minified = assert_same_dom(&doc, true);
+        assert_eq!(
+            minified,
+            "<!doctype html><meta charset=utf-8><title>Minified</title><p>One<p>Two<ul><li>a<li>b</ul><p>Three"
+        );
+    }
+
+    #[test]
+    fn kept_tags() {
+        let doc = document().with(html(lang("en")).with([
+            head([]),
+            body([]).with([
+                script(src("/app.js")),
+                div([]).with((p([]).with("text"), text(" after"))),
+                a(href("/")).with(p([]).with("link")),
+                dl([]).with([dt([]).with("term"), dd([]).with("definition")]),
+            ]),
+        ]));
+        let minified = assert_same_dom(&doc, true);
+        assert_eq!(
+            minified,
+            "<!doctype html><html lang=en><body><script src=/app.js></script><div><p>text</p> \
+             after</div><a href=/><p>link</p></a><dl><dt>term<dd>definition</dl>"
+        );
+    }
+
+    #[test]
+    fn tables_and_selects() {
+        let doc = div([]).with([
+            table([]).with([
+                thead([]).with(tr([]).with([th([]).with("A"), th([]).with("B")])),
+                tbody([]).with([
+                    tr([]).with([td([]).with("1"), td([]).with("2")]),
+                    tr([]).with(fragment().with([td([]).with("3"), td([]).with("4")])),
+                ]),
+            ]),
+            select(name("choice")).with([
+                optgroup(attr("label", "Group"))
+                    .with([option(value("a")).with("A"), option(value("b")).with("B")]),
+                option(value("c")).with("C"),
+            ]),
+        ]);
+        let minified = assert_same_dom(&doc, false);
+        assert_eq!(
+            minified,
+            "<div><table><thead><tr><th>A<th>B<tbody><tr><td>1<td>2<tr><td>3<td>4</table><select \
+             name=choice><optgroup label=Group><option value=a>A<option \
+             value=b>B</optgroup><option value=c>C</select></div>"
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        let doc = form([action("/login"), class("flex flex-col")]).with((
+            input([
+                type_("text"),
+                name("credentials[username]"),
+                value("a=b"),
+                placeholder(""),
+                required(true),
+                disabled(true),
+                checked(false),
+            ]),
+            button([type_("submit"), title_attr("it's")]).with("Log in"),
+        ));
+        let minified = assert_same_dom(&doc, false);
+        assert_eq!(
+            minified,
+            "<form action=/login class=\"flex flex-col\"><input type=text \
+             name=credentials[username] value=\"a=b\" placeholder=\"\" required disabled><button \
+             type=submit title=\"it&#x27;s\">Log in</button></form>"
+        );
+    }
+
+    #[test]
+    fn boolean_attribute_values() {
+        let doc = div([]).with((
+            div(hidden("until-found")),
+            details(open(true)),
+            input([
+                attr("disabled", ""),
+                attr("readonly", "false"),
+                attr("checked", "Checked"),
+            ]),
+            custom_tag("my-panel", attr("open", "false")),
+        ));
+        let minified = assert_same_dom(&doc, false);
+        assert_eq!(
+            minified,
+            "<div><div hidden=until-found></div><details open></details><input disabled \
+             readonly=false checked=Checked><my-panel open=false></my-panel></div>"
+        );
+    }
+
+    #[test]
+    fn root_elements_keep_end_tags() {
+        assert_eq!(li([]).with("item").to_html_minified(), "<li>item</li>");
+        assert_eq!(
+            fragment()
+                .with([p([]).with("One"), p([]).with("Two")])
+                .to_html_minified(),
+            "<p>One<p>Two</p>"
+        );
+    }
+}
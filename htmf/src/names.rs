@@ -0,0 +1,150 @@
+//! Grammars for tag and attribute names that are only known at runtime.
+
+/// Names that match the custom element grammar, but are reserved by SVG and MathML.
+const RESERVED_CUSTOM_ELEMENT_NAMES: [&str; 8] = [
+    "annotation-xml",
+    "color-profile",
+    "font-face",
+    "font-face-src",
+    "font-face-uri",
+    "font-face-format",
+    "font-face-name",
+    "missing-glyph",
+];
+
+/// Whether `name` is a [valid custom element name](https://html.spec.whatwg.org/multipage/custom-elements.html#valid-custom-element-name),
+/// like `my-widget`.
+pub(crate) fn is_valid_custom_element_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.contains('-')
+        && name.chars().all(is_custom_element_name_char)
+        && !RESERVED_CUSTOM_ELEMENT_NAMES.contains(&name)
+}
+
+/// Whether `name` can be used as the name of an element created by
+/// [`custom_tag`](crate::declare::custom_tag): either a custom element name,
+/// or an ASCII alphanumeric name like `keygen`.
+pub(crate) fn is_valid_tag_name(name: &str) -> bool {
+    is_valid_custom_element_name(name)
+        || (name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Whether `name` is a valid [attribute name](https://html.spec.whatwg.org/multipage/syntax.html#attributes-2).
+pub(crate) fn is_valid_attribute_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.chars().any(|c| {
+            c.is_control() || matches!(c, ' ' | '"' | '\'' | '>' | '/' | '=') || is_noncharacter(c)
+        })
+}
+
+/// Whether `name` is a valid [XML name](https://www.w3.org/TR/xml/#NT-Name),
+/// like the target of a processing instruction.
+pub(crate) fn is_valid_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(is_xml_name_start_char)
+        && chars.all(|c| {
+            is_xml_name_start_char(c)
+                || matches!(c, '-' | '.' | '0'..='9' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}')
+        })
+}
+
+fn is_xml_name_start_char(c: char) -> bool {
+    matches!(c,
+        ':' | 'A'..='Z' | '_' | 'a'..='z'
+        | '\u{C0}'..='\u{D6}'
+        | '\u{D8}'..='\u{F6}'
+        | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}'
+        | '\u{37F}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+fn is_noncharacter(c: char) -> bool {
+    matches!(c, '\u{FDD0}'..='\u{FDEF}') || (c as u32 & 0xFFFE) == 0xFFFE
+}
+
+fn is_custom_element_name_char(c: char) -> bool {
+    matches!(c,
+        '-' | '.' | '0'..='9' | '_' | 'a'..='z'
+        | '\u{B7}'
+        | '\u{C0}'..='\u{D6}'
+        | '\u{D8}'..='\u{F6}'
+        | '\u{F8}'..='\u{37D}'
+        | '\u{37F}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{203F}'..='\u{2040}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_element_names() {
+        for valid in ["my-widget", "x-", "a-b.c_d", "math-α", "emotion-😍"] {
+            assert!(is_valid_custom_element_name(valid), "{valid}");
+        }
+        for invalid in [
+            "widget",
+            "My-widget",
+            "-widget",
+            "my-Widget",
+            "my widget",
+            "font-face",
+        ] {
+            assert!(!is_valid_custom_element_name(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn attribute_names() {
+        for valid in [
+            "class",
+            "data-user_id",
+            "@click",
+            "x-on:click.prevent",
+            ":class",
+            "ü",
+        ] {
+            assert!(is_valid_attribute_name(valid), "{valid}");
+        }
+        for invalid in [
+            "", "x onload", "a=b", "a>", "a/", "\"a", "'a", "a\n", "\u{FFFF}",
+        ] {
+            assert!(!is_valid_attribute_name(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn tag_names() {
+        assert!(is_valid_tag_name("keygen"));
+        assert!(is_valid_tag_name("h7"));
+        assert!(!is_valid_tag_name("div>"));
+        assert!(!is_valid_tag_name(""));
+    }
+
+    #[test]
+    fn xml_names() {
+        for valid in ["xml-stylesheet", "svg:rect", "_a.1", "φ"] {
+            assert!(is_valid_xml_name(valid), "{valid}");
+        }
+        for invalid in ["", "1a", "-a", "a b", "a?"] {
+            assert!(!is_valid_xml_name(invalid), "{invalid}");
+        }
+    }
+}
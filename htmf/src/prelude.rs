@@ -1,2 +1,3 @@
 pub use crate::declare::*;
 pub use crate::element::Element;
+pub use crate::style::Style;
@@ -1,2 +1,3 @@
 pub use crate::declare_inline::*;
 pub use crate::element::Element;
+pub use crate::style::Style;
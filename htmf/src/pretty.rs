@@ -0,0 +1,481 @@
+//! Formatted HTML output with newlines and indentation.
+//!
+//! ```rust
+//! use htmf::{prelude::*, pretty::PrettyOptions};
+//!
+//! let list = ul(class("list")).with([li([]).with("One"), li([]).with("Two")]);
+//! let options = PrettyOptions::default().indent_width(4);
+//! assert_eq!(
+//!     list.to_html_pretty_with(&options).unwrap(),
+//!     "<ul class=\"list\">\n    <li>One</li>\n    <li>Two</li>\n</ul>\n"
+//! );
+//! ```
+
+use std::{borrow::Cow, fmt::Write};
+
+use crate::{
+    attr::Attrs,
+    element::Element,
+    escape,
+    render::{self, Context, Syntax, TextMode},
+    validate,
+};
+
+/// Elements that are laid out inline with surrounding text by default,
+/// taken from the phrasing content elements in the HTML standard.
+const INLINE_ELEMENTS: [&str; 37] = [
+    "a", "abbr", "b", "bdi", "bdo", "br", "button", "cite", "code", "data", "del", "dfn", "em",
+    "i", "img", "input", "ins", "kbd", "label", "mark", "meter", "output", "progress", "q", "s",
+    "samp", "select", "small", "span", "strong", "sub", "sup", "textarea", "time", "u", "var",
+    "wbr",
+];
+
+/// Configuration for [`Element::to_html_pretty_with`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct PrettyOptions {
+    indent_width: usize,
+    max_line_width: usize,
+    inline_elements: Vec<Cow<'static, str>>,
+    preserve_whitespace: Vec<Cow<'static, str>>,
+}
+
+impl PrettyOptions {
+    /// Number of spaces to indent nested elements by. Defaults to 2.
+    pub fn indent_width(mut self, indent_width: usize) -> PrettyOptions {
+        self.indent_width = indent_width;
+        self
+    }
+
+    /// Elements that don't fit into this many characters, including indentation,
+    /// are split across multiple lines. Defaults to 80.
+    ///
+    /// Text is never wrapped, so some lines can still be longer.
+    pub fn max_line_width(mut self, max_line_width: usize) -> PrettyOptions {
+        self.max_line_width = max_line_width;
+        self
+    }
+
+    /// Treat `tag` as an inline element.
+    ///
+    /// Elements that only contain text and inline elements are kept on a single line if they fit.
+    /// All other elements put each of their children on a separate line.
+    pub fn inline_element<S>(mut self, tag: S) -> PrettyOptions
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        let tag = tag.into();
+        if !self.is_inline(&tag) {
+            self.inline_elements.push(tag);
+        }
+        self
+    }
+
+    /// Treat `tag` as a block element, even if it is inline by default.
+    pub fn block_element(mut self, tag: &str) -> PrettyOptions {
+        self.inline_elements.retain(|inline| inline != tag);
+        self
+    }
+
+    /// Write the contents of `tag` exactly as they are, without adding or removing any whitespace.
+    /// This is the default for `pre` and `textarea`.
+    ///
+    /// The contents of `script` and `style` elements are always preserved.
+    pub fn preserve_whitespace_in<S>(mut self, tag: S) -> PrettyOptions
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        self.preserve_whitespace.push(tag.into());
+        self
+    }
+
+    fn is_inline(&self, tag: &str) -> bool {
+        self.inline_elements.iter().any(|inline| inline == tag)
+    }
+
+    fn preserves_whitespace(&self, tag: &str) -> bool {
+        TextMode::for_tag(tag) != TextMode::Escaped
+            || self
+                .preserve_whitespace
+                .iter()
+                .any(|preserved| preserved == tag)
+    }
+}
+
+impl Default for PrettyOptions {
+    fn default() -> Self {
+        PrettyOptions {
+            indent_width: 2,
+            max_line_width: 80,
+            inline_elements: INLINE_ELEMENTS.into_iter().map(Cow::Borrowed).collect(),
+            preserve_whitespace: vec![Cow::Borrowed("pre"), Cow::Borrowed("textarea")],
+        }
+    }
+}
+
+/// Returned when pretty printing an element fails.
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum PrettyError {
+    /// The sink passed to [`Element::write_html_pretty`] returned an error.
+    Write(std::fmt::Error),
+}
+
+impl std::fmt::Display for PrettyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrettyError::Write(_) => f.write_str("failed to write formatted HTML"),
+        }
+    }
+}
+
+impl std::error::Error for PrettyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PrettyError::Write(e) => Some(e),
+        }
+    }
+}
+
+impl From<std::fmt::Error> for PrettyError {
+    fn from(value: std::fmt::Error) -> Self {
+        PrettyError::Write(value)
+    }
+}
+
+impl Element {
+    /// Render formatted HTML using the default [`PrettyOptions`].
+    pub fn to_html_pretty(&self) -> Result<String, PrettyError> {
+        self.to_html_pretty_with(&PrettyOptions::default())
+    }
+
+    /// Render formatted HTML using the given `options`.
+    pub fn to_html_pretty_with(&self, options: &PrettyOptions) -> Result<String, PrettyError> {
+        let mut buf = String::new();
+        self.write_html_pretty(&mut buf, options)?;
+        Ok(buf)
+    }
+
+    /// Write formatted HTML into any [`std::fmt::Write`] sink.
+    pub fn write_html_pretty<W>(
+        &self,
+        f: &mut W,
+        options: &PrettyOptions,
+    ) -> Result<(), PrettyError>
+    where
+        W: Write + ?Sized,
+    {
+        validate::debug_assert_valid(self);
+        Printer { out: f, options }.block(self, 0, false)?;
+        Ok(())
+    }
+}
+
+struct Printer<'a, W: ?Sized> {
+    out: &'a mut W,
+    options: &'a PrettyOptions,
+}
+
+impl<W> Printer<'_, W>
+where
+    W: Write + ?Sized,
+{
+    /// Write `element` starting on a new line at the given `depth`.
+    fn block(
+        &mut self,
+        element: &Element,
+        depth: usize,
+        in_foreign_content: bool,
+    ) -> std::fmt::Result {
+        let cx = Context {
+            in_foreign_content,
+            ..Context::new(Syntax::Html)
+        };
+        match element {
+            Element::Nothing => {}
+            Element::Fragment { children } => {
+                for child in children {
+                    self.block(child, depth, in_foreign_content)?;
+                }
+            }
+            Element::Document { children } => {
+                self.indent(depth)?;
+                self.out.write_str("<!doctype html>\n")?;
+                for child in children {
+                    self.block(child, depth, in_foreign_content)?;
+                }
+            }
+            Element::Text { text } | Element::CData { text } => {
+                let text = text.trim();
+                if !text.is_empty() {
+                    self.indent(depth)?;
+                    escape::write_escaped_html(self.out, text)?;
+                    self.out.write_char('\n')?;
+                }
+            }
+            Element::Raw { html } => {
+                self.indent(depth)?;
+                self.out.write_str(html)?;
+                self.out.write_char('\n')?;
+            }
+            Element::ProcessingInstruction { .. } => {
+                self.indent(depth)?;
+                element.write_html_in(self.out, cx)?;
+                self.out.write_char('\n')?;
+            }
+            Element::LeafTag { tag, attrs } => {
+                self.indent(depth)?;
+                if self.fits(element, depth, cx) {
+                    element.write_html_in(self.out, cx)?;
+                } else {
+                    self.multiline_opening_tag(tag, attrs, depth, "/>", cx)?;
+                }
+                self.out.write_char('\n')?;
+            }
+            Element::Tag {
+                children,
+                tag,
+                attrs,
+            } => {
+                self.indent(depth)?;
+
+                let single_line = self.options.preserves_whitespace(tag)
+                    || (self.fits(element, depth, cx)
+                        && children.iter().all(|child| self.is_inline(child)));
+                if single_line {
+                    element.write_html_in(self.out, cx)?;
+                    self.out.write_char('\n')?;
+                    return Ok(());
+                }
+
+                if self.fits_opening_tag(element, depth, cx) {
+                    element.write_opening_html(self.out, cx)?;
+                } else {
+                    self.multiline_opening_tag(tag, attrs, depth, ">", cx)?;
+                }
+                self.out.write_char('\n')?;
+
+                let in_foreign_content =
+                    render::children_in_foreign_content(tag, in_foreign_content);
+                for child in children {
+                    self.block(child, depth + 1, in_foreign_content)?;
+                }
+
+                self.indent(depth)?;
+                element.write_closing_html(self.out, cx)?;
+                self.out.write_char('\n')?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write an opening tag with each attribute on a separate line.
+    fn multiline_opening_tag(
+        &mut self,
+        tag: &str,
+        attrs: &Attrs,
+        depth: usize,
+        end: &str,
+        cx: Context<'_>,
+    ) -> std::fmt::Result {
+        self.out.write_char('<')?;
+        escape::write_escaped_html(self.out, tag)?;
+        self.out.write_char('\n')?;
+        let foreign = render::is_foreign_element(tag, cx.in_foreign_content);
+        for attr in attrs.for_syntax(cx.syntax, foreign).rendered() {
+            self.indent(depth + 1)?;
+            attr.write_html(self.out)?;
+            self.out.write_char('\n')?;
+        }
+        self.indent(depth)?;
+        self.out.write_str(end)
+    }
+
+    fn indent(&mut self, depth: usize) -> std::fmt::Result {
+        for _ in 0..depth * self.options.indent_width {
+            self.out.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    fn is_inline(&self, element: &Element) -> bool {
+        match element {
+            Element::Text { .. } | Element::CData { .. } | Element::Nothing => true,
+            Element::Raw { .. }
+            | Element::ProcessingInstruction { .. }
+            | Element::Document { .. } => false,
+            Element::Fragment { children } => children.iter().all(|child| self.is_inline(child)),
+            Element::LeafTag { tag, .. } => self.options.is_inline(tag),
+            Element::Tag { tag, children, .. } => {
+                self.options.is_inline(tag) && children.iter().all(|child| self.is_inline(child))
+            }
+        }
+    }
+
+    /// Whether `element` fits on the rest of a line indented to `depth`.
+    fn fits(&self, element: &Element, depth: usize, cx: Context<'_>) -> bool {
+        let mut measure = Measure::new(self.available_width(depth));
+        element.write_html_in(&mut measure, cx).is_ok()
+    }
+
+    fn fits_opening_tag(&self, element: &Element, depth: usize, cx: Context<'_>) -> bool {
+        let mut measure = Measure::new(self.available_width(depth));
+        element.write_opening_html(&mut measure, cx).is_ok()
+    }
+
+    fn available_width(&self, depth: usize) -> usize {
+        self.options
+            .max_line_width
+            .saturating_sub(depth * self.options.indent_width)
+    }
+}
+
+/// A sink that only accepts a single line of up to `remaining` characters.
+struct Measure {
+    remaining: usize,
+}
+
+impl Measure {
+    fn new(remaining: usize) -> Self {
+        Measure { remaining }
+    }
+}
+
+impl Write for Measure {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let width = s.chars().count();
+        if width > self.remaining || s.contains('\n') {
+            return Err(std::fmt::Error);
+        }
+        self.remaining -= width;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn nested_blocks() {
+        let doc = document().with(html([]).with([
+            head([]).with(title_tag([]).with("Title")),
+            body([]).with([
+                nothing(),
+                p([]).with((text("Some "), strong([]).with("bold"), text(" text"))),
+                div([]).with(fragment().with([br([]), hr([])])),
+            ]),
+        ]));
+        assert_eq!(
+            doc.to_html_pretty().unwrap(),
+            "<!doctype html>
+<html>
+  <head>
+    <title>Title</title>
+  </head>
+  <body>
+    <p>Some <strong>bold</strong> text</p>
+    <div>
+      <br/>
+      <hr/>
+    </div>
+  </body>
+</html>
+"
+        );
+    }
+
+    #[test]
+    fn long_lines_are_split() {
+        let doc = p(class("a-class another-class"))
+            .with((text("Some long text "), a(href("/link")).with("and a link")));
+        let options = PrettyOptions::default().max_line_width(40);
+        assert_eq!(
+            doc.to_html_pretty_with(&options).unwrap(),
+            "<p class=\"a-class another-class\">
+  Some long text
+  <a href=\"/link\">and a link</a>
+</p>
+"
+        );
+
+        let options = PrettyOptions::default().max_line_width(20).indent_width(4);
+        assert_eq!(
+            div([id("identifier"), class("a-class another-class")])
+                .with(input([type_("text"), name("username")]))
+                .to_html_pretty_with(&options)
+                .unwrap(),
+            "<div
+    id=\"identifier\"
+    class=\"a-class another-class\"
+>
+    <input
+        type=\"text\"
+        name=\"username\"
+    />
+</div>
+"
+        );
+    }
+
+    #[test]
+    fn preserve_whitespace() {
+        let doc = div([]).with([
+            pre([]).with(" preformatted\n  text "),
+            script([]).with("if (a < b) {\n  run();\n}"),
+            code([]).with(" code "),
+        ]);
+        let options = PrettyOptions::default().preserve_whitespace_in("code");
+        assert_eq!(
+            doc.to_html_pretty_with(&options).unwrap(),
+            "<div>
+  <pre> preformatted
+  text </pre>
+  <script>if (a < b) {
+  run();
+}</script>
+  <code> code </code>
+</div>
+"
+        );
+    }
+
+    #[test]
+    fn element_classification() {
+        let doc = p([]).with((text("Text"), span([]).with("span")));
+        assert_eq!(
+            doc.to_html_pretty().unwrap(),
+            "<p>Text<span>span</span></p>\n"
+        );
+
+        let options = PrettyOptions::default().block_element("span");
+        assert_eq!(
+            doc.to_html_pretty_with(&options).unwrap(),
+            "<p>\n  Text\n  <span>span</span>\n</p>\n"
+        );
+
+        let doc = div([]).with((text("Text"), section([]).with("section")));
+        let options = PrettyOptions::default().inline_element("section");
+        assert_eq!(
+            doc.to_html_pretty_with(&options).unwrap(),
+            "<div>Text<section>section</section></div>\n"
+        );
+    }
+
+    #[test]
+    fn write_errors() {
+        struct Failing;
+
+        impl Write for Failing {
+            fn write_str(&mut self, _s: &str) -> std::fmt::Result {
+                Err(std::fmt::Error)
+            }
+        }
+
+        let result = div([]).write_html_pretty(&mut Failing, &PrettyOptions::default());
+        assert_eq!(result, Err(PrettyError::Write(std::fmt::Error)));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{element::Element, xml::XmlOptions};
+
+/// How text nodes are written, which depends on the element containing them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum TextMode {
+    /// Normal elements, as well as the escapable raw text elements `textarea` and `title`,
+    /// whose contents are decoded by browsers and therefore need escaping.
+    Escaped,
+    /// The raw text elements `script` and `style`, which browsers do not decode.
+    /// Their contents are written as-is, except for sequences that would end the element early.
+    Raw { tag: &'static str },
+}
+
+impl TextMode {
+    pub(crate) fn for_tag(tag: &str) -> TextMode {
+        match tag {
+            "script" => TextMode::Raw { tag: "script" },
+            "style" => TextMode::Raw { tag: "style" },
+            _ => TextMode::Escaped,
+        }
+    }
+}
+
+/// The flavor of markup to write.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Syntax {
+    Html,
+    Minified,
+    Xml,
+}
+
+/// Everything the traversal needs to know about the surroundings of the element it is writing.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Context<'a> {
+    pub(crate) syntax: Syntax,
+    pub(crate) text_mode: TextMode,
+    /// The closest enclosing tag.
+    pub(crate) parent: Option<&'a str>,
+    /// The element written directly after this one.
+    /// Only tracked for [`Syntax::Minified`], where it decides whether end tags can be omitted.
+    pub(crate) next_sibling: Option<&'a Element>,
+    /// The prolog written for documents in [`Syntax::Xml`].
+    pub(crate) xml_options: Option<&'a XmlOptions>,
+    /// Whether the element is inside `<svg>` or `<math>`, but not inside an HTML integration
+    /// point like `<foreignObject>`.
+    pub(crate) in_foreign_content: bool,
+}
+
+impl<'a> Context<'a> {
+    pub(crate) fn new(syntax: Syntax) -> Self {
+        Context {
+            syntax,
+            text_mode: TextMode::Escaped,
+            parent: None,
+            next_sibling: None,
+            xml_options: None,
+            in_foreign_content: false,
+        }
+    }
+
+    pub(crate) fn xml(options: &'a XmlOptions) -> Self {
+        Context {
+            xml_options: Some(options),
+            ..Context::new(Syntax::Xml)
+        }
+    }
+}
+
+/// Whether the element `tag` is an SVG or MathML element, whose attribute names are
+/// case-sensitive, given whether it is in foreign content.
+pub(crate) fn is_foreign_element(tag: &str, in_foreign_content: bool) -> bool {
+    in_foreign_content || matches!(tag, "svg" | "math")
+}
+
+/// Whether the children of the element `tag` are in foreign content.
+pub(crate) fn children_in_foreign_content(tag: &str, in_foreign_content: bool) -> bool {
+    is_foreign_element(tag, in_foreign_content)
+        && !matches!(
+            tag,
+            "foreignObject" | "desc" | "title" | "mi" | "mo" | "mn" | "ms" | "mtext"
+        )
+}
+
+/// Adapts an [`std::io::Write`] sink to [`std::fmt::Write`] so the HTML
+/// traversal can write into it directly.
+///
+/// [`std::fmt::Error`] carries no information, so the underlying I/O error
+/// is stashed here and handed back by [`IoWriter::finish`].
+pub(crate) struct IoWriter<'a, W: std::io::Write + ?Sized> {
+    inner: &'a mut W,
+    error: Option<std::io::Error>,
+}
+
+impl<'a, W: std::io::Write + ?Sized> IoWriter<'a, W> {
+    pub(crate) fn new(inner: &'a mut W) -> Self {
+        IoWriter { inner, error: None }
+    }
+
+    pub(crate) fn finish(self, result: std::fmt::Result) -> std::io::Result<()> {
+        match (result, self.error) {
+            (Ok(()), _) => Ok(()),
+            (Err(_), Some(error)) => Err(error),
+            (Err(_), None) => Err(std::io::Error::other("formatter error")),
+        }
+    }
+}
+
+impl<W: std::io::Write + ?Sized> std::fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            std::fmt::Error
+        })
+    }
+}
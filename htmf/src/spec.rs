@@ -0,0 +1,92 @@
+//! Marker types that decide which attributes an element accepts.
+//!
+//! Every tag function takes attributes for its own element type, like
+//! `Attrs<elements::A>` for [`a`](crate::declare::a), and every attribute function only
+//! creates attributes for element types that accept it. Global attributes like `class`
+//! are accepted everywhere:
+//!
+//! ```rust
+//! use htmf::prelude::*;
+//!
+//! a([class("link"), href("/")]);
+//! ```
+//!
+//! while element-specific attributes don't compile on other elements:
+//!
+//! ```rust,compile_fail
+//! use htmf::prelude::*;
+//!
+//! div(href("/"));
+//! ```
+//!
+//! [`attr`](crate::declare::attr) creates attributes for any element,
+//! and elements created with [`custom_tag`](crate::declare::custom_tag) accept all attributes.
+//!
+//! The marker types and their accepted attributes are generated from `spec/html.txt`,
+//! and from `spec/svg.txt` and `spec/mathml.txt` for the [`svg`] and [`mathml`] modules.
+
+include!(concat!(env!("OUT_DIR"), "/spec.rs"));
+
+/// Implemented by all element marker types. Elements accept all global attributes.
+pub trait ElementKind {}
+
+/// Implemented by the element marker types that accept the attribute `A`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't accept the `{A}` attribute",
+    note = "use `attr` for attributes that are not checked"
+)]
+pub trait Accepts<A> {}
+
+/// The element type that accepts all attributes, used for custom elements and
+/// for attributes that are not checked.
+pub enum AnyElement {}
+
+impl ElementKind for AnyElement {}
+
+/// Marker types for the elements and attributes of [`htmf::svg`](crate::svg),
+/// generated from `spec/svg.txt`.
+///
+/// Elements that SVG shares with HTML, like `a` and `script`, use the HTML marker types.
+pub mod svg {
+    use super::{Accepts, AnyElement, ElementKind};
+
+    include!(concat!(env!("OUT_DIR"), "/spec_svg.rs"));
+
+    /// Implemented by the SVG element marker types.
+    /// SVG elements accept the presentation attributes, like `fill` and `stroke`,
+    /// as well as the global HTML attributes.
+    pub trait SvgElementKind: ElementKind {}
+}
+
+/// Marker types for the elements and attributes of [`htmf::mathml`](crate::mathml),
+/// generated from `spec/mathml.txt`.
+pub mod mathml {
+    use super::{Accepts, AnyElement, ElementKind};
+
+    include!(concat!(env!("OUT_DIR"), "/spec_mathml.rs"));
+
+    /// Implemented by the MathML element marker types.
+    /// MathML elements accept the global MathML attributes, like `mathcolor`,
+    /// as well as the global HTML attributes.
+    pub trait MathMlElementKind: ElementKind {}
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn checked_attributes() {
+        let element = fragment().with([
+            a([class("link"), href("/"), attr("hx-boost", "true")]),
+            td([colspan(2), aria_sort(Sort::Ascending)]),
+            custom_tag("my-link", [href("/"), colspan(1)]),
+        ]);
+        assert_eq!(
+            element.to_html(),
+            r#"<a class="link" href="/" hx-boost="true"></a><td colspan="2" aria-sort="ascending"></td><my-link href="/" colspan="1"></my-link>"#
+        );
+    }
+}
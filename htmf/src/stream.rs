@@ -0,0 +1,205 @@
+//! Render elements as an asynchronous stream of byte chunks.
+//!
+//! This lets HTTP servers send the start of a page, e.g. its `<head>`,
+//! before the rest of the document has been serialized:
+//!
+//! ```rust
+//! use htmf::prelude::*;
+//!
+//! let page = html([]).with([head([]), body([]).with(p([]).with("Hello"))]);
+//! let stream = page.into_stream().chunk_size(4096);
+//! ```
+//!
+//! Bodies that expect fallible streams, like hyper's or axum's, can map the
+//! chunks with `.map(Ok::<_, std::convert::Infallible>)`.
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::{Bytes, BytesMut};
+use futures_core::Stream;
+
+use crate::{
+    element::Element,
+    render::{self, Syntax, TextMode},
+    validate,
+};
+
+/// Chunk size used by [`Element::into_stream`] unless configured otherwise.
+pub const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// A [`Stream`] of HTML chunks, created by [`Element::into_stream`].
+///
+/// Every chunk except the last one is exactly [`HtmlStream::chunk_size`] bytes long.
+/// Elements are serialized lazily, so each poll only renders as much of the
+/// tree as is needed to fill the next chunk.
+#[derive(Debug)]
+pub struct HtmlStream {
+    root: Option<Element>,
+    stack: Vec<Frame>,
+    buf: BytesMut,
+    chunk_size: usize,
+}
+
+/// An element whose opening tag has been written, along with its remaining children.
+#[derive(Debug)]
+struct Frame {
+    element: Element,
+    children: std::vec::IntoIter<Element>,
+    mode: TextMode,
+    in_foreign_content: bool,
+}
+
+impl HtmlStream {
+    fn new(root: Element) -> Self {
+        HtmlStream {
+            root: Some(root),
+            stack: Vec::new(),
+            buf: BytesMut::new(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Set the size of emitted chunks in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be greater than zero");
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Serialize the next piece of the tree into the buffer.
+    /// Returns `false` once the whole tree has been written.
+    fn advance(&mut self) -> bool {
+        if let Some(root) = self.root.take() {
+            self.enter(root, TextMode::Escaped, false);
+            return true;
+        }
+
+        let Some(frame) = self.stack.last_mut() else {
+            return false;
+        };
+
+        match frame.children.next() {
+            Some(child) => {
+                let (mode, in_foreign_content) = (frame.mode, frame.in_foreign_content);
+                self.enter(child, mode, in_foreign_content);
+            }
+            None => {
+                let frame = self.stack.pop().expect("stack is not empty");
+                frame
+                    .element
+                    .write_closing_html(&mut self.buf, render::Context::new(Syntax::Html))
+                    .expect("writing HTML to a buffer should never fail");
+            }
+        }
+
+        true
+    }
+
+    fn enter(&mut self, mut element: Element, mode: TextMode, in_foreign_content: bool) {
+        let children = element.children_mut().map(std::mem::take);
+
+        let cx = render::Context {
+            text_mode: mode,
+            in_foreign_content,
+            ..render::Context::new(Syntax::Html)
+        };
+        element
+            .write_opening_html(&mut self.buf, cx)
+            .expect("writing HTML to a buffer should never fail");
+
+        if let Some(children) = children {
+            let children_cx = element.context_for_children(cx);
+            let (mode, in_foreign_content) =
+                (children_cx.text_mode, children_cx.in_foreign_content);
+            self.stack.push(Frame {
+                element,
+                children: children.into_iter(),
+                mode,
+                in_foreign_content,
+            });
+        }
+    }
+}
+
+impl Stream for HtmlStream {
+    type Item = Bytes;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        while this.buf.len() < this.chunk_size {
+            if !this.advance() {
+                if this.buf.is_empty() {
+                    return Poll::Ready(None);
+                }
+                return Poll::Ready(Some(this.buf.split().freeze()));
+            }
+        }
+
+        Poll::Ready(Some(this.buf.split_to(this.chunk_size).freeze()))
+    }
+}
+
+impl Element {
+    /// Turn this element into a [`Stream`] of HTML chunks.
+    /// See the [module docs](crate::stream) for details.
+    pub fn into_stream(self) -> HtmlStream {
+        validate::debug_assert_valid(&self);
+        HtmlStream::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, StreamExt};
+
+    use crate::prelude::*;
+
+    fn page() -> Element {
+        document().with(
+            html([]).with([
+                head([]).with((
+                    title_tag([]).with("Streaming"),
+                    link([rel("stylesheet"), href("/style.css")]),
+                )),
+                body(class("p-4")).with(
+                    (0..100)
+                        .map(|i| p([]).with(format!("paragraph & {i}")))
+                        .collect::<Vec<_>>(),
+                ),
+            ]),
+        )
+    }
+
+    #[test]
+    fn chunks_concatenate_to_html() {
+        let chunks: Vec<_> = block_on(page().into_stream().chunk_size(64).collect());
+
+        let (last, full) = chunks.split_last().unwrap();
+        assert!(full.iter().all(|chunk| chunk.len() == 64));
+        assert!(!last.is_empty() && last.len() <= 64);
+
+        let streamed: Vec<u8> = chunks.concat();
+        assert_eq!(String::from_utf8(streamed).unwrap(), page().to_html());
+    }
+
+    #[test]
+    fn head_arrives_first() {
+        let mut stream = page().into_stream().chunk_size(64);
+        let first = block_on(stream.next()).unwrap();
+        assert!(first.starts_with(b"<!doctype html><html><head><title>"));
+    }
+
+    #[test]
+    fn empty_element() {
+        let chunks: Vec<_> = block_on(nothing().into_stream().collect());
+        assert!(chunks.is_empty());
+    }
+}
@@ -0,0 +1,233 @@
+//! A builder for inline `style` attributes.
+
+use std::{borrow::Cow, fmt::Write};
+
+use crate::{
+    attr::{Attr, AttrValue, Attrs, IntoAttrValue},
+    into_attrs::IntoAttrs,
+};
+
+/// CSS declarations for a `style` attribute.
+///
+/// Values are escaped so that they can't end their declaration early or break out
+/// of the attribute. Like attribute values, `None` leaves out a declaration.
+///
+/// ```rust
+/// use htmf::prelude::*;
+///
+/// let progress = 40;
+/// let color: Option<&str> = None;
+/// let bar = div(
+///     Style::new()
+///         .property("width", format!("{progress}%"))
+///         .property("color", color)
+///         .var("brand-color", "#ff5500"),
+/// );
+/// assert_eq!(bar.to_html(), r#"<div style="width:40%;--brand-color:#ff5500"></div>"#);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Style {
+    declarations: Vec<(String, String)>,
+}
+
+impl Style {
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    /// Add the declaration `name: value`. Setting a property again replaces its earlier value.
+    ///
+    /// `name` may include a vendor prefix like `-webkit-`, or be a custom property like `--gap`.
+    /// Property names are ASCII case-insensitive and written in lowercase, while custom
+    /// properties are case-sensitive and kept as they are.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is not a valid property name, like `"color:red;x"`.
+    pub fn property<C, M>(mut self, name: &str, value: C) -> Style
+    where
+        C: IntoAttrValue<M>,
+    {
+        if !is_valid_property_name(name) {
+            panic!("invalid CSS property name {name:?}");
+        }
+        let AttrValue::String(value) = value.into_attr_value() else {
+            return self;
+        };
+
+        let name = match name.starts_with("--") {
+            true => name.to_string(),
+            false => name.to_ascii_lowercase(),
+        };
+        let value = escape_value(value.trim());
+        match self.declarations.iter_mut().find(|(n, _)| *n == name) {
+            Some(declaration) => declaration.1 = value,
+            None => self.declarations.push((name, value)),
+        }
+        self
+    }
+
+    /// Set the CSS variable `--name`. The leading dashes are optional.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is not a valid custom property name, like `"a b"`.
+    pub fn var<C, M>(self, name: &str, value: C) -> Style
+    where
+        C: IntoAttrValue<M>,
+    {
+        let name = name.strip_prefix("--").unwrap_or(name);
+        self.property(&format!("--{name}"), value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+}
+
+impl std::fmt::Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (name, value)) in self.declarations.iter().enumerate() {
+            if i > 0 {
+                f.write_char(';')?;
+            }
+            write!(f, "{name}:{value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E> From<Style> for Attrs<E> {
+    fn from(style: Style) -> Self {
+        let value = match style.is_empty() {
+            true => AttrValue::Absent,
+            false => AttrValue::String(style.to_string()),
+        };
+        Attr(Cow::Borrowed("style"), value).into()
+    }
+}
+
+impl<E> IntoAttrs<E> for Style {
+    fn into_attrs(self) -> Attrs {
+        self.into()
+    }
+}
+
+fn is_valid_property_name(name: &str) -> bool {
+    if let Some(custom) = name.strip_prefix("--") {
+        return !custom.is_empty()
+            && custom
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_') || !c.is_ascii());
+    }
+
+    let name = name.strip_prefix('-').unwrap_or(name);
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// Escape `value` so that it stays within its declaration:
+/// strings and parentheses are closed, and characters that end declarations or blocks
+/// outside of them are written as CSS escapes.
+fn escape_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (_, '\\' | '<' | '>' | '\n' | '\r' | '\u{c}') => {
+                let _ = write!(out, "\\{:x} ", c as u32);
+            }
+            (Some(q), c) if c == q => {
+                quote = None;
+                out.push(c);
+            }
+            (Some(_), c) => out.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                out.push(c);
+            }
+            (None, '(') => {
+                depth += 1;
+                out.push(c);
+            }
+            (None, ')') if depth > 0 => {
+                depth -= 1;
+                out.push(c);
+            }
+            (None, '/') if chars.peek() == Some(&'*') => out.push_str("\\2f "),
+            (None, ';' | '{' | '}' | ')') => {
+                let _ = write!(out, "\\{:x} ", c as u32);
+            }
+            (None, c) => out.push(c),
+        }
+    }
+
+    if let Some(q) = quote {
+        out.push(q);
+    }
+    for _ in 0..depth {
+        out.push(')');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Style;
+
+    #[test]
+    fn declarations() {
+        let style = Style::new()
+            .property("Width", "50%")
+            .property("-webkit-user-select", "none")
+            .property("font-family", r#""Open Sans", sans-serif"#)
+            .property("background", "url(/a.png)")
+            .var("--gap", "4px")
+            .property("width", "100%");
+        assert_eq!(
+            style.to_string(),
+            r#"width:100%;-webkit-user-select:none;font-family:"Open Sans", sans-serif;background:url(/a.png);--gap:4px"#
+        );
+    }
+
+    #[test]
+    fn custom_properties_keep_their_case() {
+        let style = Style::new()
+            .var("brandColor", "red")
+            .property("--brandColor", "blue")
+            .property("--brandcolor", "green");
+        assert_eq!(style.to_string(), "--brandColor:blue;--brandcolor:green");
+    }
+
+    #[test]
+    fn invalid_names() {
+        let invalid = [
+            || Style::new().property("color:red;x", "1"),
+            || Style::new().property("", "1"),
+            || Style::new().property("--", None::<&str>),
+            || Style::new().var("a b", "1"),
+            || Style::new().property("2d", "1"),
+        ];
+        for (i, style) in invalid.into_iter().enumerate() {
+            assert!(std::panic::catch_unwind(style).is_err(), "{i}");
+        }
+    }
+
+    #[test]
+    fn escape_values() {
+        let style = Style::new()
+            .property("color", "red; background: url(evil)")
+            .property("width", "calc(100% /* x")
+            .property("content", "'a;b")
+            .property("margin", "0 } body { x");
+        assert_eq!(
+            style.to_string(),
+            r"color:red\3b  background: url(evil);width:calc(100% \2f * x);content:'a;b';margin:0 \7d  body \7b  x"
+        );
+    }
+}
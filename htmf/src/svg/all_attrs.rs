@@ -0,0 +1,25 @@
+use std::borrow::Cow;
+
+use crate::attr::Attr;
+use crate::attr::Attrs;
+use crate::attr::IntoAttrValue;
+use crate::spec::{svg::attributes, Accepts};
+
+// Take care to name the parameter `value`
+// to disable rust analyzer inlay hints
+macro_rules! define_attr_function {
+    ($name:ident, html) => {
+        pub use crate::declare::$name;
+    };
+    ($name:ident, $key:literal, $kind:ident) => {
+        pub fn $name<E, C, M>(value: C) -> Attrs<E>
+        where
+            E: Accepts<attributes::$kind>,
+            C: IntoAttrValue<M>,
+        {
+            Attr(Cow::Borrowed($key), value.into_attr_value()).into()
+        }
+    };
+}
+
+svg_attributes!(define_attr_function);
@@ -0,0 +1,31 @@
+use std::borrow::Cow;
+
+use crate::{element::Element, into_attrs::IntoAttrs, spec::svg::elements};
+
+macro_rules! define_tag_function {
+    ($tag:ident, html) => {
+        pub use crate::declare::$tag;
+    };
+
+    ($tag:ident, $kind:ident) => {
+        pub fn $tag<Attrs: IntoAttrs<elements::$kind>>(value: Attrs) -> Element {
+            Element::Tag {
+                tag: Cow::Borrowed(stringify!($tag)),
+                attrs: value.into_attrs(),
+                children: Vec::new(),
+            }
+        }
+    };
+
+    ($tag:ident, $kind:ident, $tag_str:literal) => {
+        pub fn $tag<Attrs: IntoAttrs<elements::$kind>>(value: Attrs) -> Element {
+            Element::Tag {
+                tag: Cow::Borrowed($tag_str),
+                attrs: value.into_attrs(),
+                children: Vec::new(),
+            }
+        }
+    };
+}
+
+svg_elements!(define_tag_function);
@@ -0,0 +1,134 @@
+//! SVG elements and attributes, generated from `spec/svg.txt`.
+//!
+//! Names keep their case in the output, like `viewBox` and `linearGradient`,
+//! while the functions use snake case, like [`view_box`] and [`linear_gradient`].
+//! [`PathData`] and [`Transform`] build the values of the `d` and `transform` attributes.
+//!
+//! ```rust
+//! use htmf::prelude::*;
+//! use htmf::svg::{self, PathData, Transform};
+//!
+//! let icon = svg::svg([svg::view_box("0 0 24 24"), width(24), height(24)]).with((
+//!     svg::path([
+//!         svg::d(PathData::new().move_to(4.0, 12.0).line_to(10.0, 18.0).line_to(20.0, 6.0)),
+//!         svg::fill("none"),
+//!         svg::stroke("currentColor"),
+//!         svg::stroke_width(2),
+//!     ]),
+//!     svg::circle([
+//!         svg::r(2),
+//!         svg::transform(Transform::new().translate(12.0, 12.0)),
+//!     ]),
+//! ));
+//! assert_eq!(
+//!     icon.to_html(),
+//!     r#"<svg viewBox="0 0 24 24" width="24" height="24"><path d="M4 12 L10 18 L20 6" fill="none" stroke="currentColor" stroke-width="2"></path><circle r="2" transform="translate(12 12)"></circle></svg>"#
+//! );
+//! ```
+//!
+//! Import the module itself instead of its contents, since the [`text`] element
+//! collides with the [`text`](crate::declare::text) function of the [`prelude`](crate::prelude).
+//! Elements and attributes that SVG shares with HTML, like [`svg`], [`a`], [`class`] and [`href`],
+//! are re-exported from [`declare`](crate::declare).
+//!
+//! Like HTML attributes, SVG attributes are only accepted by the elements they apply to:
+//!
+//! ```rust,compile_fail
+//! use htmf::svg;
+//!
+//! svg::circle(svg::d("M0 0"));
+//! ```
+//!
+//! See [`svg_inline`](crate::svg_inline) for SVG elements in the style of
+//! [`declare_inline`](crate::declare_inline).
+
+pub(crate) mod all_attrs;
+mod all_tags;
+mod path;
+mod transform;
+
+pub use all_attrs::*;
+pub use all_tags::*;
+pub use path::PathData;
+pub use transform::Transform;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{self as svg, PathData, Transform};
+    use crate::prelude::*;
+
+    #[test]
+    fn case_preserving_names() {
+        let gradient = svg::defs([]).with(
+            svg::linear_gradient([
+                id("fade"),
+                svg::gradient_transform(Transform::new().rotate(90.0)),
+            ])
+            .with(svg::stop([svg::offset("0"), svg::stop_color("white")])),
+        );
+        let element = svg::svg([
+            svg::view_box("0 0 10 10"),
+            svg::preserve_aspect_ratio("xMidYMid meet"),
+        ])
+        .with((
+            gradient,
+            svg::rect([width(10), height(10), svg::fill("url(#fade)")]),
+            svg::text([svg::x(1), svg::y(9)]).with("a < b"),
+        ));
+        assert_eq!(
+            element.to_xml(),
+            r#"<svg viewBox="0 0 10 10" preserveAspectRatio="xMidYMid meet"><defs><linearGradient id="fade" gradientTransform="rotate(90)"><stop offset="0" stop-color="white"/></linearGradient></defs><rect width="10" height="10" fill="url(#fade)"/><text x="1" y="9">a &lt; b</text></svg>"#
+        );
+    }
+
+    #[test]
+    fn path_data() {
+        let path = PathData::new()
+            .move_to(0.0, 0.5)
+            .horizontal_by(-10.0)
+            .vertical_to(2.0)
+            .cubic_to(1.0, 2.0, 3.0, 4.0, 5.0, 6.0)
+            .smooth_quadratic_by(1.5, 0.0)
+            .arc_to(5.0, 5.0, 0.0, true, false, 10.0, 10.0)
+            .close();
+        assert_eq!(
+            path.to_string(),
+            "M0 0.5 h-10 V2 C1 2 3 4 5 6 t1.5 0 A5 5 0 1 0 10 10 Z"
+        );
+        assert!(PathData::new().is_empty());
+    }
+
+    #[test]
+    fn transforms() {
+        let transform = Transform::new()
+            .scale(2.0, 1.0)
+            .rotate_around(-45.0, 5.0, 5.0)
+            .skew_x(10.0)
+            .matrix(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+        assert_eq!(
+            transform.to_string(),
+            "scale(2 1) rotate(-45 5 5) skewX(10) matrix(1 0 0 1 0 0)"
+        );
+    }
+
+    #[test]
+    #[should_panic = "SVG numbers must be finite"]
+    fn non_finite_numbers() {
+        PathData::new().move_to(f64::NAN, 0.0);
+    }
+
+    #[test]
+    fn shared_with_html() {
+        let link = svg::a([href("/"), svg::fill("red")]).with(svg::circle(svg::r(1)));
+        assert_eq!(
+            link.to_html(),
+            r#"<a href="/" fill="red"><circle r="1"></circle></a>"#
+        );
+        assert_eq!(
+            svg::image([href("/a.png"), svg::x(0)]).to_html(),
+            r#"<image href="/a.png" x="0"></image>"#
+        );
+    }
+}
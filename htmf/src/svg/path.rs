@@ -0,0 +1,192 @@
+use std::fmt::Write;
+
+/// Path data for the [`d`](super::d) attribute of `<path>` elements.
+///
+/// Each method appends a command. The `_to` methods take absolute coordinates,
+/// and the `_by` methods take coordinates relative to the current point:
+///
+/// ```rust
+/// use htmf::svg::{self, PathData};
+///
+/// let check = PathData::new()
+///     .move_to(4.0, 12.0)
+///     .line_by(6.0, 6.0)
+///     .line_to(20.0, 6.0);
+/// assert_eq!(
+///     svg::path(svg::d(check)).to_html(),
+///     r#"<path d="M4 12 l6 6 L20 6"></path>"#
+/// );
+/// ```
+///
+/// # Panics
+///
+/// The methods panic if a number is not finite, like `f64::NAN`.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct PathData {
+    data: String,
+}
+
+impl PathData {
+    pub fn new() -> PathData {
+        PathData::default()
+    }
+
+    /// `M`: start a new subpath at (`x`, `y`).
+    pub fn move_to(self, x: f64, y: f64) -> PathData {
+        self.command('M', &[x, y])
+    }
+
+    /// `m`: start a new subpath, relative to the current point.
+    pub fn move_by(self, dx: f64, dy: f64) -> PathData {
+        self.command('m', &[dx, dy])
+    }
+
+    /// `L`: draw a line to (`x`, `y`).
+    pub fn line_to(self, x: f64, y: f64) -> PathData {
+        self.command('L', &[x, y])
+    }
+
+    /// `l`: draw a line, relative to the current point.
+    pub fn line_by(self, dx: f64, dy: f64) -> PathData {
+        self.command('l', &[dx, dy])
+    }
+
+    /// `H`: draw a horizontal line to `x`.
+    pub fn horizontal_to(self, x: f64) -> PathData {
+        self.command('H', &[x])
+    }
+
+    /// `h`: draw a horizontal line, relative to the current point.
+    pub fn horizontal_by(self, dx: f64) -> PathData {
+        self.command('h', &[dx])
+    }
+
+    /// `V`: draw a vertical line to `y`.
+    pub fn vertical_to(self, y: f64) -> PathData {
+        self.command('V', &[y])
+    }
+
+    /// `v`: draw a vertical line, relative to the current point.
+    pub fn vertical_by(self, dy: f64) -> PathData {
+        self.command('v', &[dy])
+    }
+
+    /// `C`: draw a cubic Bézier curve to (`x`, `y`) with the control points
+    /// (`x1`, `y1`) and (`x2`, `y2`).
+    pub fn cubic_to(self, x1: f64, y1: f64, x2: f64, y2: f64, x: f64, y: f64) -> PathData {
+        self.command('C', &[x1, y1, x2, y2, x, y])
+    }
+
+    /// `c`: draw a cubic Bézier curve, relative to the current point.
+    pub fn cubic_by(self, dx1: f64, dy1: f64, dx2: f64, dy2: f64, dx: f64, dy: f64) -> PathData {
+        self.command('c', &[dx1, dy1, dx2, dy2, dx, dy])
+    }
+
+    /// `S`: draw a cubic Bézier curve to (`x`, `y`) whose first control point
+    /// reflects the second control point of the previous curve.
+    pub fn smooth_cubic_to(self, x2: f64, y2: f64, x: f64, y: f64) -> PathData {
+        self.command('S', &[x2, y2, x, y])
+    }
+
+    /// `s`: draw a smooth cubic Bézier curve, relative to the current point.
+    pub fn smooth_cubic_by(self, dx2: f64, dy2: f64, dx: f64, dy: f64) -> PathData {
+        self.command('s', &[dx2, dy2, dx, dy])
+    }
+
+    /// `Q`: draw a quadratic Bézier curve to (`x`, `y`) with the control point (`x1`, `y1`).
+    pub fn quadratic_to(self, x1: f64, y1: f64, x: f64, y: f64) -> PathData {
+        self.command('Q', &[x1, y1, x, y])
+    }
+
+    /// `q`: draw a quadratic Bézier curve, relative to the current point.
+    pub fn quadratic_by(self, dx1: f64, dy1: f64, dx: f64, dy: f64) -> PathData {
+        self.command('q', &[dx1, dy1, dx, dy])
+    }
+
+    /// `T`: draw a quadratic Bézier curve to (`x`, `y`) whose control point
+    /// reflects the control point of the previous curve.
+    pub fn smooth_quadratic_to(self, x: f64, y: f64) -> PathData {
+        self.command('T', &[x, y])
+    }
+
+    /// `t`: draw a smooth quadratic Bézier curve, relative to the current point.
+    pub fn smooth_quadratic_by(self, dx: f64, dy: f64) -> PathData {
+        self.command('t', &[dx, dy])
+    }
+
+    /// `A`: draw an elliptical arc to (`x`, `y`) with the radii `rx` and `ry`,
+    /// rotated by `x_axis_rotation` degrees.
+    /// `large_arc` and `sweep` choose which of the four possible arcs is drawn.
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_to(
+        self,
+        rx: f64,
+        ry: f64,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        x: f64,
+        y: f64,
+    ) -> PathData {
+        let flags = [f64::from(u8::from(large_arc)), f64::from(u8::from(sweep))];
+        self.command('A', &[rx, ry, x_axis_rotation, flags[0], flags[1], x, y])
+    }
+
+    /// `a`: draw an elliptical arc, relative to the current point.
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_by(
+        self,
+        rx: f64,
+        ry: f64,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        dx: f64,
+        dy: f64,
+    ) -> PathData {
+        let flags = [f64::from(u8::from(large_arc)), f64::from(u8::from(sweep))];
+        self.command('a', &[rx, ry, x_axis_rotation, flags[0], flags[1], dx, dy])
+    }
+
+    /// `Z`: close the current subpath with a line to its start.
+    pub fn close(self) -> PathData {
+        self.command('Z', &[])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn command(mut self, command: char, numbers: &[f64]) -> PathData {
+        if !self.data.is_empty() {
+            self.data.push(' ');
+        }
+        self.data.push(command);
+        write_numbers(&mut self.data, numbers);
+        self
+    }
+}
+
+impl std::fmt::Display for PathData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.data)
+    }
+}
+
+/// Append `numbers` separated by spaces.
+///
+/// # Panics
+///
+/// If a number is not finite.
+pub(crate) fn write_numbers(out: &mut String, numbers: &[f64]) {
+    for (i, number) in numbers.iter().enumerate() {
+        assert!(
+            number.is_finite(),
+            "SVG numbers must be finite, got {number}"
+        );
+        if i > 0 {
+            out.push(' ');
+        }
+        let _ = write!(out, "{number}");
+    }
+}
@@ -0,0 +1,86 @@
+use super::path::write_numbers;
+
+/// A list of transform functions for the [`transform`](super::transform),
+/// `gradientTransform` and `patternTransform` attributes.
+///
+/// Functions are applied from right to left, so the last one is applied first:
+///
+/// ```rust
+/// use htmf::svg::{self, Transform};
+///
+/// let rotated = Transform::new().translate(50.0, 50.0).rotate(45.0);
+/// assert_eq!(
+///     svg::g(svg::transform(rotated)).to_html(),
+///     r#"<g transform="translate(50 50) rotate(45)"></g>"#
+/// );
+/// ```
+///
+/// # Panics
+///
+/// The methods panic if a number is not finite, like `f64::NAN`.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Transform {
+    functions: String,
+}
+
+impl Transform {
+    pub fn new() -> Transform {
+        Transform::default()
+    }
+
+    /// Move by `x` and `y`.
+    pub fn translate(self, x: f64, y: f64) -> Transform {
+        self.function("translate", &[x, y])
+    }
+
+    /// Scale by `x` horizontally and `y` vertically.
+    pub fn scale(self, x: f64, y: f64) -> Transform {
+        self.function("scale", &[x, y])
+    }
+
+    /// Rotate by `angle` degrees around the origin.
+    pub fn rotate(self, angle: f64) -> Transform {
+        self.function("rotate", &[angle])
+    }
+
+    /// Rotate by `angle` degrees around the point (`cx`, `cy`).
+    pub fn rotate_around(self, angle: f64, cx: f64, cy: f64) -> Transform {
+        self.function("rotate", &[angle, cx, cy])
+    }
+
+    /// Skew along the x axis by `angle` degrees.
+    pub fn skew_x(self, angle: f64) -> Transform {
+        self.function("skewX", &[angle])
+    }
+
+    /// Skew along the y axis by `angle` degrees.
+    pub fn skew_y(self, angle: f64) -> Transform {
+        self.function("skewY", &[angle])
+    }
+
+    /// Apply the transformation matrix `[a c e; b d f; 0 0 1]`.
+    pub fn matrix(self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Transform {
+        self.function("matrix", &[a, b, c, d, e, f])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    fn function(mut self, name: &str, numbers: &[f64]) -> Transform {
+        if !self.functions.is_empty() {
+            self.functions.push(' ');
+        }
+        self.functions.push_str(name);
+        self.functions.push('(');
+        write_numbers(&mut self.functions, numbers);
+        self.functions.push(')');
+        self
+    }
+}
+
+impl std::fmt::Display for Transform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.functions)
+    }
+}
@@ -0,0 +1,18 @@
+//! The SVG elements of [`htmf::svg`](crate::svg), taking their children as second argument
+//! like the functions of [`declare_inline`](crate::declare_inline).
+//!
+//! ```rust
+//! use htmf::svg_inline as svg;
+//!
+//! let dot = svg::svg(svg::view_box("0 0 2 2"), svg::circle([svg::cx(1), svg::cy(1), svg::r(1)], ()));
+//! assert_eq!(
+//!     dot.to_html(),
+//!     r#"<svg viewBox="0 0 2 2"><circle cx="1" cy="1" r="1"></circle></svg>"#
+//! );
+//! ```
+
+mod all_tags;
+
+pub use crate::svg::all_attrs::*;
+pub use crate::svg::{PathData, Transform};
+pub use all_tags::*;
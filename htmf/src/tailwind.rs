@@ -0,0 +1,455 @@
+//! Conflict resolution for [Tailwind CSS](https://tailwindcss.com) utility classes.
+//!
+//! Two utilities conflict if they set the same CSS property under the same variants,
+//! like `px-2` and `px-4`, or `md:text-sm` and `md:text-lg`. Only the last of them is kept.
+//! Utilities that set a property for several sides, like `p-4`, also replace earlier
+//! utilities for single sides, like `pt-2`. Unknown classes are always kept.
+
+use std::collections::HashSet;
+
+/// Utilities without a value, and the group of utilities they conflict with.
+const STANDALONE: [(&str, &str); 47] = [
+    ("block", "display"),
+    ("inline-block", "display"),
+    ("inline", "display"),
+    ("flex", "display"),
+    ("inline-flex", "display"),
+    ("grid", "display"),
+    ("inline-grid", "display"),
+    ("table", "display"),
+    ("table-row", "display"),
+    ("table-cell", "display"),
+    ("contents", "display"),
+    ("list-item", "display"),
+    ("flow-root", "display"),
+    ("hidden", "display"),
+    ("static", "position"),
+    ("fixed", "position"),
+    ("absolute", "position"),
+    ("relative", "position"),
+    ("sticky", "position"),
+    ("visible", "visibility"),
+    ("invisible", "visibility"),
+    ("collapse", "visibility"),
+    ("uppercase", "text-transform"),
+    ("lowercase", "text-transform"),
+    ("capitalize", "text-transform"),
+    ("normal-case", "text-transform"),
+    ("italic", "font-style"),
+    ("not-italic", "font-style"),
+    ("underline", "text-decoration"),
+    ("overline", "text-decoration"),
+    ("line-through", "text-decoration"),
+    ("no-underline", "text-decoration"),
+    ("sr-only", "sr"),
+    ("not-sr-only", "sr"),
+    ("truncate", "text-overflow"),
+    ("text-ellipsis", "text-overflow"),
+    ("text-clip", "text-overflow"),
+    ("antialiased", "font-smoothing"),
+    ("subpixel-antialiased", "font-smoothing"),
+    ("rounded", "rounded"),
+    ("border", "border"),
+    ("shadow", "shadow"),
+    ("ring", "ring"),
+    ("grow", "grow"),
+    ("shrink", "shrink"),
+    ("transition", "transition"),
+    ("container", "container"),
+];
+
+/// Utilities taking a value. Each of them forms its own group,
+/// except for those that are split up further by [`group`].
+const PREFIXES: [&str; 114] = [
+    "p",
+    "px",
+    "py",
+    "pt",
+    "pr",
+    "pb",
+    "pl",
+    "ps",
+    "pe",
+    "m",
+    "mx",
+    "my",
+    "mt",
+    "mr",
+    "mb",
+    "ml",
+    "ms",
+    "me",
+    "gap",
+    "gap-x",
+    "gap-y",
+    "space-x",
+    "space-y",
+    "w",
+    "h",
+    "size",
+    "min-w",
+    "min-h",
+    "max-w",
+    "max-h",
+    "inset",
+    "inset-x",
+    "inset-y",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "start",
+    "end",
+    "z",
+    "order",
+    "opacity",
+    "basis",
+    "grow",
+    "shrink",
+    "flex",
+    "justify",
+    "justify-items",
+    "justify-self",
+    "items",
+    "self",
+    "content",
+    "place-content",
+    "place-items",
+    "place-self",
+    "grid-cols",
+    "grid-rows",
+    "grid-flow",
+    "auto-cols",
+    "auto-rows",
+    "col",
+    "col-span",
+    "col-start",
+    "col-end",
+    "row",
+    "row-span",
+    "row-start",
+    "row-end",
+    "text",
+    "font",
+    "leading",
+    "tracking",
+    "whitespace",
+    "align",
+    "line-clamp",
+    "bg",
+    "bg-clip",
+    "bg-origin",
+    "border",
+    "border-x",
+    "border-y",
+    "border-t",
+    "border-r",
+    "border-b",
+    "border-l",
+    "border-s",
+    "border-e",
+    "rounded",
+    "rounded-t",
+    "rounded-r",
+    "rounded-b",
+    "rounded-l",
+    "rounded-s",
+    "rounded-e",
+    "rounded-tl",
+    "rounded-tr",
+    "rounded-br",
+    "rounded-bl",
+    "shadow",
+    "ring",
+    "ring-offset",
+    "outline",
+    "overflow",
+    "overflow-x",
+    "overflow-y",
+    "cursor",
+    "select",
+    "pointer-events",
+    "aspect",
+    "columns",
+    "duration",
+    "delay",
+    "ease",
+    "animate",
+];
+
+const FONT_SIZES: [&str; 13] = [
+    "xs", "sm", "base", "lg", "xl", "2xl", "3xl", "4xl", "5xl", "6xl", "7xl", "8xl", "9xl",
+];
+
+const FONT_WEIGHTS: [&str; 9] = [
+    "thin",
+    "extralight",
+    "light",
+    "normal",
+    "medium",
+    "semibold",
+    "bold",
+    "extrabold",
+    "black",
+];
+
+/// Keep only the last of each set of conflicting utilities in the space-separated `classes`.
+pub(crate) fn merge(classes: &str) -> String {
+    let mut claimed = HashSet::new();
+    let mut seen = HashSet::new();
+    let mut kept = Vec::new();
+
+    for class in classes.split_ascii_whitespace().rev() {
+        if !seen.insert(class) {
+            continue;
+        }
+
+        if let Some((modifiers, group)) = parse(class) {
+            if claimed.contains(&(modifiers.clone(), group.clone())) {
+                continue;
+            }
+            for overridden in overrides(&group) {
+                claimed.insert((modifiers.clone(), overridden.to_string()));
+            }
+            claimed.insert((modifiers, group));
+        }
+
+        kept.push(class);
+    }
+
+    kept.reverse();
+    kept.join(" ")
+}
+
+/// Split `class` into its modifiers, like `md:hover:` or `!`, and the group of its utility.
+fn parse(class: &str) -> Option<(String, String)> {
+    let mut depth = 0usize;
+    let mut utility_start = 0;
+    for (i, c) in class.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 => utility_start = i + 1,
+            _ => {}
+        }
+    }
+
+    let (variants, utility) = class.split_at(utility_start);
+    // Important utilities are written as `!p-2` in Tailwind 3 and `p-2!` in Tailwind 4
+    let important = utility.starts_with('!') || utility.ends_with('!');
+    let utility = utility.trim_start_matches('!').trim_end_matches('!');
+    let utility = utility.strip_prefix('-').unwrap_or(utility);
+    let group = group(utility)?;
+
+    let mut modifiers = variants.to_string();
+    if important {
+        modifiers.push('!');
+    }
+    Some((modifiers, group))
+}
+
+/// Values of `border-style` and `outline-style`.
+const LINE_STYLES: [&str; 6] = ["solid", "dashed", "dotted", "double", "hidden", "none"];
+
+fn group(utility: &str) -> Option<String> {
+    if let Some((_, group)) = STANDALONE.iter().find(|(name, _)| *name == utility) {
+        return Some(group.to_string());
+    }
+
+    let (prefix, value) = PREFIXES
+        .iter()
+        .filter_map(|prefix| {
+            let value = utility.strip_prefix(prefix)?.strip_prefix('-')?;
+            Some((*prefix, value))
+        })
+        .max_by_key(|(prefix, _)| prefix.len())?;
+    if value.is_empty() {
+        return None;
+    }
+
+    let group = match prefix {
+        "text" if FONT_SIZES.contains(&value) => "font-size",
+        "text" if ["left", "center", "right", "justify", "start", "end"].contains(&value) => {
+            "text-align"
+        }
+        "text" if value.starts_with('[') && !is_color(value) => "font-size",
+        "text" => "text-color",
+        "font" if FONT_WEIGHTS.contains(&value) => "font-weight",
+        "font" => "font-family",
+        "flex" if ["row", "row-reverse", "col", "col-reverse"].contains(&value) => "flex-direction",
+        "flex" if ["wrap", "wrap-reverse", "nowrap"].contains(&value) => "flex-wrap",
+        "bg" if ["fixed", "local", "scroll"].contains(&value) => "bg-attachment",
+        "bg" if ["auto", "cover", "contain"].contains(&value) => "bg-size",
+        "bg" if value.starts_with("repeat") || value == "no-repeat" => "bg-repeat",
+        "bg" if ["bottom", "center", "left", "right", "top"]
+            .iter()
+            .any(|position| value.starts_with(position)) =>
+        {
+            "bg-position"
+        }
+        "bg" if value == "none" || value.starts_with("gradient") => "bg-image",
+        "bg" => "bg-color",
+        // `content-none` and `content-['x']` set `content`, the others `align-content`
+        "content" if value == "none" || value.starts_with('[') => "content",
+        "content" => "align-content",
+        "border" if LINE_STYLES.contains(&value) => "border-style",
+        "border" if ["collapse", "separate"].contains(&value) => "border-collapse",
+        // `border-t` is the width of a single side, like `border-t-2`
+        "border" if ["x", "y", "t", "r", "b", "l", "s", "e"].contains(&value) => {
+            return Some(format!("border-{value}"));
+        }
+        "outline" if LINE_STYLES.contains(&value) => "outline-style",
+        "ring" if value == "inset" => "ring-inset",
+        "shadow" if ["sm", "md", "lg", "xl", "2xl", "inner", "none"].contains(&value) => "shadow",
+        "shadow" => "shadow-color",
+        prefix
+            if prefix.starts_with("border")
+                || prefix.starts_with("ring")
+                || prefix == "outline" =>
+        {
+            return Some(match is_length(value) {
+                true => prefix.to_string(),
+                false => format!("{prefix}-color"),
+            });
+        }
+        prefix => prefix,
+    };
+
+    Some(group.to_string())
+}
+
+/// Groups of single sides or axes that a utility for several of them replaces.
+fn overrides(group: &str) -> &'static [&'static str] {
+    match group {
+        "p" => &["px", "py", "pt", "pr", "pb", "pl", "ps", "pe"],
+        "px" => &["pr", "pl", "ps", "pe"],
+        "py" => &["pt", "pb"],
+        "m" => &["mx", "my", "mt", "mr", "mb", "ml", "ms", "me"],
+        "mx" => &["mr", "ml", "ms", "me"],
+        "my" => &["mt", "mb"],
+        "gap" => &["gap-x", "gap-y"],
+        "size" => &["w", "h"],
+        "inset" => &[
+            "inset-x", "inset-y", "top", "right", "bottom", "left", "start", "end",
+        ],
+        "inset-x" => &["right", "left", "start", "end"],
+        "inset-y" => &["top", "bottom"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "rounded" => &[
+            "rounded-t",
+            "rounded-r",
+            "rounded-b",
+            "rounded-l",
+            "rounded-s",
+            "rounded-e",
+            "rounded-tl",
+            "rounded-tr",
+            "rounded-br",
+            "rounded-bl",
+        ],
+        "rounded-t" => &["rounded-tl", "rounded-tr"],
+        "rounded-r" => &["rounded-tr", "rounded-br"],
+        "rounded-b" => &["rounded-br", "rounded-bl"],
+        "rounded-l" => &["rounded-tl", "rounded-bl"],
+        "border" => &[
+            "border-x", "border-y", "border-t", "border-r", "border-b", "border-l", "border-s",
+            "border-e",
+        ],
+        "border-x" => &["border-r", "border-l", "border-s", "border-e"],
+        "border-y" => &["border-t", "border-b"],
+        _ => &[],
+    }
+}
+
+fn is_length(value: &str) -> bool {
+    match value
+        .strip_prefix('[')
+        .and_then(|value| value.strip_suffix(']'))
+    {
+        Some(arbitrary) => !is_color(arbitrary),
+        None => value.chars().all(|c| c.is_ascii_digit() || c == '.'),
+    }
+}
+
+fn is_color(value: &str) -> bool {
+    let value = value.trim_start_matches('[');
+    value.starts_with('#')
+        || ["rgb", "hsl", "oklch", "color:"]
+            .iter()
+            .any(|function| value.starts_with(function))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::merge;
+
+    #[test]
+    fn later_utility_wins() {
+        assert_eq!(merge("px-2 py-1 px-4"), "py-1 px-4");
+        assert_eq!(
+            merge("text-sm text-red-500 text-lg"),
+            "text-red-500 text-lg"
+        );
+        assert_eq!(
+            merge("flex hidden font-bold font-sans"),
+            "hidden font-bold font-sans"
+        );
+        assert_eq!(
+            merge("border border-red-500 border-2"),
+            "border-red-500 border-2"
+        );
+        assert_eq!(merge("-mt-2 mt-4"), "mt-4");
+    }
+
+    #[test]
+    fn keywords_are_not_colors() {
+        assert_eq!(merge("border-t border-red-500"), "border-t border-red-500");
+        assert_eq!(merge("border-t border-t-2"), "border-t-2");
+        assert_eq!(
+            merge("outline-none outline-red-500"),
+            "outline-none outline-red-500"
+        );
+        assert_eq!(merge("outline-dashed outline-none"), "outline-none");
+        assert_eq!(
+            merge("ring-inset ring-blue-500"),
+            "ring-inset ring-blue-500"
+        );
+        assert_eq!(
+            merge("border-collapse border-gray-200"),
+            "border-collapse border-gray-200"
+        );
+    }
+
+    #[test]
+    fn content() {
+        assert_eq!(
+            merge("content-center content-['*']"),
+            "content-center content-['*']"
+        );
+        assert_eq!(merge("content-start content-between"), "content-between");
+        assert_eq!(merge("content-none content-['*']"), "content-['*']");
+    }
+
+    #[test]
+    fn sides() {
+        assert_eq!(merge("pt-2 px-1 p-4"), "p-4");
+        assert_eq!(merge("p-4 pt-2"), "p-4 pt-2");
+        assert_eq!(merge("rounded-tl-lg rounded-t-none"), "rounded-t-none");
+    }
+
+    #[test]
+    fn variants() {
+        assert_eq!(
+            merge("p-2 md:p-2 hover:p-3 md:p-4 !p-1"),
+            "p-2 hover:p-3 md:p-4 !p-1"
+        );
+        assert_eq!(merge("w-[10px] w-[calc(100%-1rem)]"), "w-[calc(100%-1rem)]");
+    }
+
+    #[test]
+    fn unknown_classes() {
+        assert_eq!(merge("btn card btn p-2"), "card btn p-2");
+    }
+}
@@ -0,0 +1,285 @@
+//! Sanitization for attributes that contain URLs, like `href` or `src`.
+//!
+//! Attribute functions such as [`href`](crate::declare::href) check their value against a
+//! [`UrlPolicy`]. URLs with a scheme that is not on the policy's allowlist, like
+//! `javascript:alert(1)`, are replaced by [`UNSAFE_URL_PLACEHOLDER`]:
+//!
+//! ```rust
+//! use htmf::prelude::*;
+//!
+//! assert_eq!(
+//!     a(href("javascript:alert(1)")).to_html(),
+//!     r#"<a href="about:invalid#htmf-unsafe-url"></a>"#
+//! );
+//!
+//! // Opt out of sanitization for URLs you trust
+//! assert_eq!(
+//!     a(href(trusted_url_unchecked("javascript:void(0)"))).to_html(),
+//!     r#"<a href="javascript:void(0)"></a>"#
+//! );
+//! ```
+//!
+//! Relative URLs are always allowed. Attributes created using [`attr`](crate::declare::attr)
+//! are never sanitized.
+
+use std::{borrow::Cow, cell::RefCell, marker::PhantomData};
+
+use crate::attr::{markers, AttrValue};
+
+/// Replaces URLs that are not allowed by the active [`UrlPolicy`].
+pub const UNSAFE_URL_PLACEHOLDER: &str = "about:invalid#htmf-unsafe-url";
+
+/// The schemes allowed by [`UrlPolicy::default`].
+const DEFAULT_SCHEMES: [&str; 3] = ["http", "https", "mailto"];
+
+thread_local! {
+    /// The policy set by [`with_policy`], or `None` for the default policy.
+    static POLICY: RefCell<Option<UrlPolicy>> = const { RefCell::new(None) };
+}
+
+/// An allowlist of URL schemes.
+///
+/// The default policy allows `http`, `https` and `mailto` URLs.
+/// Use [`with_policy`] to change the policy used by URL attribute functions:
+///
+/// ```rust
+/// use htmf::{prelude::*, url::{self, UrlPolicy}};
+///
+/// let _policy = url::with_policy(UrlPolicy::default().allow_scheme("tel"));
+/// assert_eq!(a(href("tel:123")).to_html(), r#"<a href="tel:123"></a>"#);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct UrlPolicy {
+    allowed_schemes: Vec<Cow<'static, str>>,
+}
+
+impl UrlPolicy {
+    /// A policy that only allows relative URLs.
+    pub fn relative_only() -> UrlPolicy {
+        UrlPolicy {
+            allowed_schemes: Vec::new(),
+        }
+    }
+
+    /// Allow URLs using `scheme`, e.g. `"tel"`. Schemes are compared case-insensitively.
+    pub fn allow_scheme<S>(mut self, scheme: S) -> UrlPolicy
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        let scheme = scheme.into();
+        let scheme = match scheme.bytes().any(|b| b.is_ascii_uppercase()) {
+            true => Cow::Owned(scheme.to_ascii_lowercase()),
+            false => scheme,
+        };
+        self.allowed_schemes.push(scheme);
+        self
+    }
+
+    pub fn is_allowed(&self, url: &str) -> bool {
+        is_allowed_by(&self.allowed_schemes, url)
+    }
+
+    /// Return `url` if it is allowed, and [`UNSAFE_URL_PLACEHOLDER`] otherwise.
+    pub fn sanitize(&self, url: String) -> String {
+        sanitize_with(self.is_allowed(&url), url)
+    }
+}
+
+impl Default for UrlPolicy {
+    fn default() -> Self {
+        DEFAULT_SCHEMES
+            .into_iter()
+            .fold(UrlPolicy::relative_only(), UrlPolicy::allow_scheme)
+    }
+}
+
+/// Use `policy` for the URL attributes created on the current thread,
+/// until the returned guard is dropped. Dropping it restores the previous policy.
+///
+/// Other threads keep using their own policy, so tests that set a policy don't affect
+/// each other. Set the policy in the thread that builds the elements.
+pub fn with_policy(policy: UrlPolicy) -> UrlPolicyGuard {
+    UrlPolicyGuard {
+        previous: POLICY.replace(Some(policy)),
+        _not_send: PhantomData,
+    }
+}
+
+/// Restores the previous [`UrlPolicy`] when dropped. Created by [`with_policy`].
+#[must_use = "the policy is only used until the guard is dropped"]
+pub struct UrlPolicyGuard {
+    previous: Option<UrlPolicy>,
+    /// The guard has to be dropped on the thread whose policy it changed.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for UrlPolicyGuard {
+    fn drop(&mut self) {
+        POLICY.set(self.previous.take());
+    }
+}
+
+fn is_allowed_by<S>(allowed_schemes: &[S], url: &str) -> bool
+where
+    S: AsRef<str>,
+{
+    match scheme(url) {
+        None => true,
+        Some(scheme) => allowed_schemes
+            .iter()
+            .any(|allowed| allowed.as_ref().eq_ignore_ascii_case(&scheme)),
+    }
+}
+
+fn sanitize_with(allowed: bool, url: String) -> String {
+    if allowed {
+        url
+    } else {
+        UNSAFE_URL_PLACEHOLDER.to_string()
+    }
+}
+
+/// Extract the scheme of `url` the way browsers do,
+/// or `None` if `url` is relative.
+fn scheme(url: &str) -> Option<String> {
+    // Browsers ignore leading control characters and spaces,
+    // and tabs and newlines anywhere in the URL, so `java\tscript:` is still `javascript:`
+    let url = url.trim_start_matches(|c: char| c.is_ascii_control() || c == ' ');
+    let mut chars = url.chars().filter(|c| !matches!(c, '\t' | '\n' | '\r'));
+
+    let mut scheme = String::new();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() => scheme.push(c),
+        _ => return None,
+    }
+
+    for c in chars {
+        match c {
+            ':' => return Some(scheme),
+            c if c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.') => scheme.push(c),
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+/// A URL that is trusted to be safe and skips sanitization.
+/// Create it using [`trusted_url_unchecked`](crate::declare::trusted_url_unchecked).
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TrustedUrl(pub(crate) String);
+
+/// Values accepted by URL attribute functions like [`href`](crate::declare::href).
+///
+/// Like [`IntoAttrValue`](crate::attr::IntoAttrValue), this is also implemented for `Option`s,
+/// which leave out the attribute when they are `None`.
+pub trait IntoUrl<M> {
+    /// Convert into an attribute value, sanitizing it if necessary.
+    fn into_url(self) -> AttrValue;
+}
+
+impl<T> IntoUrl<markers::Value> for T
+where
+    T: ToString,
+{
+    fn into_url(self) -> AttrValue {
+        let url = self.to_string();
+        let url = POLICY.with_borrow(|policy| match policy {
+            Some(policy) => policy.sanitize(url),
+            None => {
+                let allowed = is_allowed_by(&DEFAULT_SCHEMES, &url);
+                sanitize_with(allowed, url)
+            }
+        });
+        AttrValue::String(url)
+    }
+}
+
+impl IntoUrl<markers::Value> for TrustedUrl {
+    fn into_url(self) -> AttrValue {
+        AttrValue::String(self.0)
+    }
+}
+
+impl<T> IntoUrl<markers::OptionalValue> for Option<T>
+where
+    T: IntoUrl<markers::Value>,
+{
+    fn into_url(self) -> AttrValue {
+        match self {
+            Some(url) => url.into_url(),
+            None => AttrValue::Absent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_policy() {
+        let policy = UrlPolicy::default();
+        for allowed in [
+            "/path",
+            "path/with:colon",
+            "?query=a:b",
+            "#fragment:x",
+            "//example.com",
+            "http://example.com",
+            "HTTPS://example.com",
+            "mailto:me@example.com",
+            "",
+        ] {
+            assert!(policy.is_allowed(allowed), "{allowed}");
+        }
+
+        for disallowed in [
+            "javascript:alert(1)",
+            "JaVaScRiPt:alert(1)",
+            " \u{1}javascript:alert(1)",
+            "java\tscr\nipt:alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+            "vbscript:msgbox",
+            "tel:123",
+        ] {
+            assert!(!policy.is_allowed(disallowed), "{disallowed}");
+        }
+    }
+
+    #[test]
+    fn custom_policy() {
+        let policy = UrlPolicy::relative_only().allow_scheme("TEL");
+        assert!(policy.is_allowed("tel:123"));
+        assert!(policy.is_allowed("/relative"));
+        assert_eq!(
+            policy.sanitize("https://example.com".to_string()),
+            UNSAFE_URL_PLACEHOLDER
+        );
+    }
+
+    #[test]
+    fn scoped_policy() {
+        use crate::prelude::*;
+
+        let link = || a(href("tel:123")).to_html();
+        assert_eq!(link(), r#"<a href="about:invalid#htmf-unsafe-url"></a>"#);
+        {
+            let _tel = with_policy(UrlPolicy::default().allow_scheme("tel"));
+            assert_eq!(link(), r#"<a href="tel:123"></a>"#);
+            {
+                let _relative = with_policy(UrlPolicy::relative_only());
+                assert_eq!(
+                    a(href("https://example.com")).to_html(),
+                    r#"<a href="about:invalid#htmf-unsafe-url"></a>"#
+                );
+            }
+            assert_eq!(link(), r#"<a href="tel:123"></a>"#);
+            assert_eq!(
+                std::thread::spawn(link).join().unwrap(),
+                r#"<a href="about:invalid#htmf-unsafe-url"></a>"#
+            );
+        }
+        assert_eq!(link(), r#"<a href="about:invalid#htmf-unsafe-url"></a>"#);
+    }
+}
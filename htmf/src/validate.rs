@@ -0,0 +1,580 @@
+//! Checks element trees against the
+//! [content models](https://html.spec.whatwg.org/multipage/dom.html#content-models)
+//! of the HTML standard.
+//!
+//! [`Element::validate`] reports elements that browsers would move or drop while parsing,
+//! like a `<div>` inside a `<p>` or a `<tr>` directly inside a `<table>`:
+//!
+//! ```rust
+//! use htmf::prelude::*;
+//!
+//! let page = body([]).with((
+//!     p([]).with(div([])),
+//!     ul([]).with(li([]).with("One")),
+//! ));
+//! let diagnostics = page.validate();
+//! assert_eq!(diagnostics.len(), 1);
+//! assert_eq!(
+//!     diagnostics[0].to_string(),
+//!     "body > p[0] > div[0]: `div` is not allowed in `p`"
+//! );
+//! ```
+//!
+//! The rules cover the common mistakes, not the whole standard. Elements at the root of
+//! the tree are not required to have a parent, so partials like a single `<li>` for an
+//! htmx swap are valid. Contents of `<template>`, `<svg>` and `<math>` elements, raw HTML and
+//! elements created with [`custom_tag`](crate::declare::custom_tag) are not checked.
+//!
+//! # Checking in tests
+//!
+//! With the "debug-validate" feature, rendering HTML using [`Element::to_html`],
+//! [`Element::to_html_minified`], [`Element::to_html_pretty`], their `write` variants or
+//! `Element::into_stream` validates the element first, and panics with all diagnostics
+//! if there are any.
+//! This only happens in builds with debug assertions, so enabling the feature for
+//! dev-dependencies checks every view rendered in tests:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! htmf = { version = "*", features = ["debug-validate"] }
+//! ```
+
+use std::{borrow::Cow, fmt::Write};
+
+use crate::{
+    attr::{AttrValue, Attrs},
+    element::Element,
+};
+
+/// A content model violation found by [`Element::validate`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Diagnostic {
+    /// Where the offending node is.
+    pub path: NodePath,
+    pub problem: Problem,
+}
+
+/// What is wrong with a node.
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum Problem {
+    /// `child` can't be a child of `parent`, like a `<div>` in a `<p>`.
+    /// `child` is `#text` for text that is not whitespace.
+    NotAllowedIn {
+        parent: Cow<'static, str>,
+        child: Cow<'static, str>,
+    },
+    /// `tag` can only be a child of one of `parents`, like `<li>` of `<ul>`, `<ol>` or `<menu>`.
+    MissingParent {
+        tag: Cow<'static, str>,
+        parents: &'static [&'static str],
+    },
+    /// `tag` can't be anywhere inside `ancestor`, like a `<form>` in another `<form>`
+    /// or a `<button>` in an `<a>`.
+    NotAllowedInside {
+        ancestor: Cow<'static, str>,
+        tag: Cow<'static, str>,
+    },
+}
+
+/// The position of a node in an element tree.
+///
+/// Each step is the name of an element and its index among the children of the previous one.
+/// Fragments and documents show up as `#fragment` and `#document`, and text as `#text`.
+/// Fragments passed to [`Element::with`] are flattened into their parent.
+/// It is displayed like `body > p[0] > div[2]`.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct NodePath {
+    steps: Vec<(usize, Cow<'static, str>)>,
+}
+
+impl NodePath {
+    /// The indices leading from the root to the node.
+    /// The first index, for the root itself, is always 0.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.steps.iter().map(|(index, _)| *index)
+    }
+
+    /// The names of the nodes from the root to the node itself.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.steps.iter().map(|(_, name)| &**name)
+    }
+
+    pub(crate) fn push(&mut self, index: usize, name: Cow<'static, str>) {
+        self.steps.push((index, name));
+    }
+
+    pub(crate) fn pop(&mut self) {
+        self.steps.pop();
+    }
+}
+
+impl std::fmt::Display for NodePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (index, name)) in self.steps.iter().enumerate() {
+            if i == 0 {
+                f.write_str(name)?;
+            } else {
+                write!(f, " > {name}[{index}]")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::NotAllowedIn { parent, child } if child == "#text" => {
+                write!(f, "text is not allowed in `{parent}`")
+            }
+            Problem::NotAllowedIn { parent, child } => {
+                write!(f, "`{child}` is not allowed in `{parent}`")
+            }
+            Problem::MissingParent { tag, parents } => {
+                write!(f, "`{tag}` must be a child of ")?;
+                for (i, parent) in parents.iter().enumerate() {
+                    match i {
+                        0 => {}
+                        _ if i == parents.len() - 1 => f.write_str(" or ")?,
+                        _ => f.write_str(", ")?,
+                    }
+                    write!(f, "`{parent}`")?;
+                }
+                Ok(())
+            }
+            Problem::NotAllowedInside { ancestor, tag } => {
+                write!(f, "`{tag}` is not allowed inside `{ancestor}`")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.problem)
+    }
+}
+
+impl Element {
+    /// Check this element and its descendants against the HTML content models.
+    /// See the [module docs](crate::validate) for details.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validator = Validator::default();
+        validator.visit(self, 0, Scope::default());
+        validator.diagnostics
+    }
+}
+
+/// Panic with all diagnostics if `element` is not valid,
+/// when the "debug-validate" feature and debug assertions are enabled.
+pub(crate) fn debug_assert_valid(element: &Element) {
+    if cfg!(all(feature = "debug-validate", debug_assertions)) {
+        let diagnostics = element.validate();
+        if !diagnostics.is_empty() {
+            let mut message = String::from("invalid HTML:");
+            for diagnostic in &diagnostics {
+                let _ = write!(message, "\n  {diagnostic}");
+            }
+            panic!("{message}");
+        }
+    }
+}
+
+/// Elements whose children are restricted to a fixed set of elements.
+/// Whitespace is allowed in all of them, other text is not.
+fn allowed_children(parent: &str) -> Option<&'static [&'static str]> {
+    Some(match parent {
+        "html" => &["head", "body"],
+        "head" => &[
+            "base", "link", "meta", "noscript", "script", "style", "template", "title",
+        ],
+        // `tr` is missing on purpose: browsers wrap it in an implicit `tbody`.
+        "table" => &[
+            "caption", "colgroup", "thead", "tbody", "tfoot", "script", "template",
+        ],
+        "thead" | "tbody" | "tfoot" => &["tr", "script", "template"],
+        "tr" => &["td", "th", "script", "template"],
+        "colgroup" => &["col", "template"],
+        "ul" | "ol" | "menu" => &["li", "script", "template"],
+        "dl" => &["dt", "dd", "div", "script", "template"],
+        "select" => &["option", "optgroup", "hr", "script", "template"],
+        "optgroup" => &["option", "script", "template"],
+        "picture" => &["source", "img", "script", "template"],
+        _ => return None,
+    })
+}
+
+/// Elements that are only allowed as children of certain elements.
+fn required_parents(tag: &str) -> Option<&'static [&'static str]> {
+    Some(match tag {
+        "li" => &["ul", "ol", "menu"],
+        "dt" | "dd" => &["dl", "div"],
+        "tr" => &["thead", "tbody", "tfoot"],
+        "td" | "th" => &["tr"],
+        "thead" | "tbody" | "tfoot" | "caption" | "colgroup" => &["table"],
+        "col" => &["colgroup"],
+        "option" => &["select", "datalist", "optgroup"],
+        "optgroup" => &["select"],
+        "legend" => &["fieldset"],
+        "figcaption" => &["figure"],
+        "summary" => &["details"],
+        "source" => &["audio", "video", "picture"],
+        "track" => &["audio", "video"],
+        "rt" | "rp" => &["ruby"],
+        "head" | "body" => &["html"],
+        _ => return None,
+    })
+}
+
+/// Elements whose content is restricted to
+/// [phrasing content](https://html.spec.whatwg.org/multipage/dom.html#phrasing-content-2).
+const PHRASING_PARENTS: [&str; 34] = [
+    "abbr", "b", "bdi", "bdo", "button", "cite", "code", "data", "dfn", "em", "h1", "h2", "h3",
+    "h4", "h5", "h6", "i", "kbd", "label", "mark", "output", "p", "pre", "q", "s", "samp", "small",
+    "span", "strong", "sub", "sup", "time", "u", "var",
+];
+
+/// Elements that are not phrasing content, and therefore not allowed in [`PHRASING_PARENTS`].
+const FLOW_ELEMENTS: [&str; 48] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "caption",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "legend",
+    "li",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "search",
+    "section",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+];
+
+/// Elements whose content model is that of their parent, so they don't lift the
+/// phrasing restriction of [`PHRASING_PARENTS`].
+const TRANSPARENT: [&str; 7] = ["a", "audio", "canvas", "del", "ins", "map", "video"];
+
+/// [Interactive content](https://html.spec.whatwg.org/multipage/dom.html#interactive-content-2),
+/// which is not allowed inside `a` and `button`.
+fn is_interactive(tag: &str, attrs: &Attrs) -> bool {
+    match tag {
+        "a" | "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
+        "input" => !matches!(
+            attrs.get("type"),
+            Some(AttrValue::String(type_)) if type_.eq_ignore_ascii_case("hidden")
+        ),
+        _ => false,
+    }
+}
+
+/// What the content model of the surrounding elements allows.
+#[derive(Clone, Copy, Default)]
+struct Scope<'a> {
+    /// The closest enclosing element.
+    /// `None` at the root and directly inside `template`.
+    parent: Option<&'a str>,
+    /// The closest enclosing element that only allows phrasing content,
+    /// unless there is a non-transparent element in between.
+    phrasing: Option<&'a str>,
+    in_form: bool,
+    in_label: bool,
+    /// The closest enclosing `a` or `button`.
+    interactive: Option<&'a str>,
+}
+
+#[derive(Default)]
+struct Validator {
+    path: NodePath,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator {
+    fn visit<'a>(&mut self, element: &'a Element, index: usize, scope: Scope<'a>) {
+        match element {
+            Element::Tag { tag, attrs, .. } | Element::LeafTag { tag, attrs } => {
+                self.path.push(index, tag.clone());
+                self.check_element(tag, attrs, scope);
+                if let Element::Tag { children, .. } = element {
+                    if !matches!(&**tag, "svg" | "math") {
+                        let scope = Self::scope_for_children(tag, scope);
+                        for (i, child) in children.iter().enumerate() {
+                            self.visit(child, i, scope);
+                        }
+                    }
+                }
+                self.path.pop();
+            }
+            Element::Fragment { children } | Element::Document { children } => {
+                let (name, scope) = match element {
+                    Element::Fragment { .. } => ("#fragment", scope),
+                    _ => ("#document", Scope::default()),
+                };
+                self.path.push(index, Cow::Borrowed(name));
+                for (i, child) in children.iter().enumerate() {
+                    self.visit(child, i, scope);
+                }
+                self.path.pop();
+            }
+            Element::Text { text } | Element::CData { text } => {
+                let Some(parent) = scope.parent else {
+                    return;
+                };
+                if allowed_children(parent).is_some()
+                    && !text
+                        .trim_matches(|c: char| c.is_ascii_whitespace())
+                        .is_empty()
+                {
+                    self.path.push(index, Cow::Borrowed("#text"));
+                    self.report(Problem::NotAllowedIn {
+                        parent: owned(parent),
+                        child: Cow::Borrowed("#text"),
+                    });
+                    self.path.pop();
+                }
+            }
+            Element::Raw { .. } | Element::ProcessingInstruction { .. } | Element::Nothing => {}
+        }
+    }
+
+    fn check_element(&mut self, tag: &str, attrs: &Attrs, scope: Scope<'_>) {
+        let not_allowed_in = match (scope.parent, scope.phrasing) {
+            (Some(parent), _)
+                if allowed_children(parent).is_some_and(|allowed| !allowed.contains(&tag)) =>
+            {
+                Some(parent)
+            }
+            (_, Some(phrasing)) if FLOW_ELEMENTS.contains(&tag) => Some(phrasing),
+            _ => None,
+        };
+        if let Some(parent) = not_allowed_in {
+            self.report(Problem::NotAllowedIn {
+                parent: owned(parent),
+                child: owned(tag),
+            });
+        } else if let (Some(parent), Some(parents)) = (scope.parent, required_parents(tag)) {
+            if !parents.contains(&parent) && !parent.contains('-') {
+                self.report(Problem::MissingParent {
+                    tag: owned(tag),
+                    parents,
+                });
+            }
+        }
+
+        let ancestor = match tag {
+            "form" if scope.in_form => Some("form"),
+            "label" if scope.in_label => Some("label"),
+            _ if is_interactive(tag, attrs) => scope.interactive,
+            _ => None,
+        };
+        if let Some(ancestor) = ancestor {
+            self.report(Problem::NotAllowedInside {
+                ancestor: owned(ancestor),
+                tag: owned(tag),
+            });
+        }
+    }
+
+    fn scope_for_children<'a>(tag: &'a str, scope: Scope<'a>) -> Scope<'a> {
+        if tag == "template" {
+            return Scope::default();
+        }
+        let phrasing = if PHRASING_PARENTS.contains(&tag) {
+            Some(tag)
+        } else if TRANSPARENT.contains(&tag) {
+            scope.phrasing
+        } else {
+            None
+        };
+        Scope {
+            parent: Some(tag),
+            phrasing,
+            in_form: scope.in_form || tag == "form",
+            in_label: scope.in_label || tag == "label",
+            interactive: match tag {
+                "a" | "button" => Some(tag),
+                _ => scope.interactive,
+            },
+        }
+    }
+
+    fn report(&mut self, problem: Problem) {
+        self.diagnostics.push(Diagnostic {
+            path: self.path.clone(),
+            problem,
+        });
+    }
+}
+
+fn owned(name: &str) -> Cow<'static, str> {
+    Cow::Owned(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::prelude::*;
+
+    fn diagnostics(element: Element) -> Vec<String> {
+        element.validate().iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn valid() {
+        let page = document().with(html([]).with((
+            head([]).with(title_tag([]).with("Page")),
+            body([]).with((
+                p([]).with((text("a "), a(href("/")).with(strong([]).with("b")))),
+                a(href("/")).with(div([]).with("card")),
+                ul([]).with((li([]).with(p([]).with("One")), li([]).with("Two"))),
+                table([]).with(tbody([]).with(tr([]).with((th([]).with("a"), td([]))))),
+                dl([]).with(div([]).with((dt([]).with("a"), dd([]).with("b")))),
+                form([]).with((
+                    label([]).with((text("Name"), input(name("name")))),
+                    button([]).with((input(type_("hidden")), span([]).with("Send"))),
+                )),
+                select([]).with(optgroup([]).with(option([]).with("a"))),
+                template([]).with(li([]).with(div([]))),
+                custom_tag("my-list", []).with(li([])),
+            )),
+        )));
+        assert_eq!(diagnostics(page), Vec::<String>::new());
+    }
+
+    #[test]
+    fn not_allowed_in() {
+        let element = div([]).with((
+            p([]).with((text("a"), span([]).with(a([]).with(div([]))))),
+            h1([]).with(p([])),
+            ul([]).with((text("\n  "), li([]), div([]), text("loose"))),
+            table([]).with(fragment().with(tr([]).with(td([])))),
+        ));
+        assert_eq!(
+            diagnostics(element),
+            [
+                "div > p[0] > span[1] > a[0] > div[0]: `div` is not allowed in `span`",
+                "div > h1[1] > p[0]: `p` is not allowed in `h1`",
+                "div > ul[2] > div[2]: `div` is not allowed in `ul`",
+                "div > ul[2] > #text[3]: text is not allowed in `ul`",
+                "div > table[3] > tr[0]: `tr` is not allowed in `table`",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_parent() {
+        let element = div([]).with((
+            li([]),
+            section([]).with(td([])),
+            article([]).with(dd([])),
+            fieldset([]).with(legend([]).with("Name")),
+            figure([]).with(div([]).with(figcaption([]))),
+        ));
+        assert_eq!(
+            diagnostics(element),
+            [
+                "div > li[0]: `li` must be a child of `ul`, `ol` or `menu`",
+                "div > section[1] > td[0]: `td` must be a child of `tr`",
+                "div > article[2] > dd[0]: `dd` must be a child of `dl` or `div`",
+                "div > figure[4] > div[0] > figcaption[0]: `figcaption` must be a child of `figure`",
+            ]
+        );
+    }
+
+    #[test]
+    fn root_elements_have_no_parent() {
+        assert_eq!(diagnostics(li([]).with("Item")), Vec::<String>::new());
+        assert_eq!(
+            diagnostics(fragment().with((tr([]).with(td([])), option([])))),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn not_allowed_inside() {
+        let element = form([]).with((
+            div([]).with(form([])),
+            label([]).with(span([]).with(label([]))),
+            a(href("/")).with((button([]), input([]), input(type_("hidden")))),
+            button([]).with(fragment().with(a([]))),
+        ));
+        assert_eq!(
+            diagnostics(element),
+            [
+                "form > div[0] > form[0]: `form` is not allowed inside `form`",
+                "form > label[1] > span[0] > label[0]: `label` is not allowed inside `label`",
+                "form > a[2] > button[0]: `button` is not allowed inside `a`",
+                "form > a[2] > input[1]: `input` is not allowed inside `a`",
+                "form > button[3] > a[0]: `a` is not allowed inside `button`",
+            ]
+        );
+    }
+
+    #[test]
+    fn foreign_and_raw_content() {
+        let element = p([]).with((
+            custom_tag("svg", []).with(custom_tag("foreignObject", []).with(div([]))),
+            trusted_html_unchecked("<div></div>"),
+        ));
+        assert_eq!(diagnostics(element), Vec::<String>::new());
+    }
+
+    #[test]
+    fn path() {
+        let element =
+            document().with(html([]).with(body([]).with((nothing(), p([]).with(div([]))))));
+        let diagnostic = &element.validate()[0];
+        assert_eq!(
+            diagnostic.path.indices().collect::<Vec<_>>(),
+            [0, 0, 0, 1, 0]
+        );
+        assert_eq!(
+            diagnostic.path.names().collect::<Vec<_>>(),
+            ["#document", "html", "body", "p", "div"]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-validate", debug_assertions))]
+    #[should_panic = "invalid HTML:\n  p > div[0]: `div` is not allowed in `p`"]
+    fn debug_assertions() {
+        p([]).with(div([])).to_html();
+    }
+}
@@ -0,0 +1,234 @@
+//! XML output, for XHTML documents, RSS feeds or SVG files.
+//!
+//! Compared to HTML output, [`Element::to_xml`]
+//!
+//! - writes an XML declaration and an optional doctype for [`document`](crate::declare::document),
+//! - closes all elements without children using `/>`, like `<div/>`,
+//! - escapes text and attribute values following the XML rules, also inside `script` and `style`,
+//! - writes boolean attributes with their name as value, like `checked="checked"`,
+//! - and writes [`cdata`](crate::declare::cdata) as CDATA sections.
+//!
+//! Tag and attribute names are written exactly as given, so names like `viewBox` keep their case.
+//!
+//! ```rust
+//! use htmf::{prelude::*, xml::XmlOptions};
+//!
+//! let page = document().with(html(attr("xmlns", "http://www.w3.org/1999/xhtml")).with(
+//!     body([]).with((
+//!         input([type_("checkbox"), checked(true)]),
+//!         script([]).with("if (a < b) {}"),
+//!         div([]),
+//!     )),
+//! ));
+//! assert_eq!(
+//!     page.to_xml_with(&XmlOptions::xhtml()),
+//!     r#"<?xml version="1.0" encoding="UTF-8"?><!DOCTYPE html><html xmlns="http://www.w3.org/1999/xhtml"><body><input type="checkbox" checked="checked"/><script>if (a &lt; b) {}</script><div/></body></html>"#
+//! );
+//! ```
+//!
+//! Elements that are not part of HTML can be created using [`custom_tag`](crate::declare::custom_tag):
+//!
+//! ```rust
+//! use htmf::prelude::*;
+//!
+//! let feed = document().with((
+//!     processing_instruction("xml-stylesheet", r#"href="/feed.xsl" type="text/xsl""#),
+//!     custom_tag("rss", attr("version", "2.0")).with(
+//!         custom_tag("channel", []).with(custom_tag("description", []).with(cdata("<p>News</p>"))),
+//!     ),
+//! ));
+//! assert_eq!(
+//!     feed.to_xml(),
+//!     r#"<?xml version="1.0" encoding="UTF-8"?><?xml-stylesheet href="/feed.xsl" type="text/xsl"?><rss version="2.0"><channel><description><![CDATA[<p>News</p>]]></description></channel></rss>"#
+//! );
+//! ```
+
+use std::{borrow::Cow, fmt::Write};
+
+use crate::{element::Element, render::Context};
+
+/// Configuration for [`Element::to_xml_with`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct XmlOptions {
+    declaration: bool,
+    doctype: Option<Cow<'static, str>>,
+}
+
+impl XmlOptions {
+    /// Options for XHTML documents, which start with an XML declaration and `<!DOCTYPE html>`.
+    pub fn xhtml() -> XmlOptions {
+        XmlOptions::default().doctype("html")
+    }
+
+    /// Whether documents start with `<?xml version="1.0" encoding="UTF-8"?>`. Defaults to `true`.
+    pub fn declaration(mut self, declaration: bool) -> XmlOptions {
+        self.declaration = declaration;
+        self
+    }
+
+    /// Write `<!DOCTYPE doctype>` at the start of documents, after the XML declaration.
+    /// By default, no doctype is written.
+    ///
+    /// `doctype` is written as-is, like `html` or
+    /// `svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd"`.
+    ///
+    /// # Panics
+    ///
+    /// If `doctype` contains `>`, which would end the doctype early.
+    pub fn doctype<S>(mut self, doctype: S) -> XmlOptions
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        let doctype = doctype.into();
+        assert!(!doctype.contains('>'), "invalid doctype {doctype:?}");
+        self.doctype = Some(doctype);
+        self
+    }
+
+    /// Write the XML declaration and doctype.
+    pub(crate) fn write_prolog<W>(&self, f: &mut W) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        if self.declaration {
+            f.write_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        }
+        if let Some(doctype) = &self.doctype {
+            f.write_str("<!DOCTYPE ")?;
+            f.write_str(doctype)?;
+            f.write_char('>')?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for XmlOptions {
+    fn default() -> Self {
+        XmlOptions {
+            declaration: true,
+            doctype: None,
+        }
+    }
+}
+
+impl Element {
+    /// Render XML using the default [`XmlOptions`].
+    pub fn to_xml(&self) -> String {
+        self.to_xml_with(&XmlOptions::default())
+    }
+
+    /// Render XML using the given `options`.
+    pub fn to_xml_with(&self, options: &XmlOptions) -> String {
+        let mut buf = String::new();
+        self.write_xml(&mut buf, options)
+            .expect("writing XML to a String should never fail");
+        buf
+    }
+
+    /// Write XML into any [`std::fmt::Write`] sink.
+    pub fn write_xml<W>(&self, f: &mut W, options: &XmlOptions) -> std::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        self.write_html_in(f, Context::xml(options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::XmlOptions;
+    use crate::prelude::*;
+
+    #[test]
+    fn self_closing() {
+        let element = div([]).with((
+            span([]),
+            p([]).with(("", nothing(), fragment())),
+            br([]),
+            p([]).with(""),
+        ));
+        assert_eq!(element.to_xml(), "<div><span/><p/><br/><p/></div>");
+        assert_eq!(div([]).with(span([])).to_xml(), "<div><span/></div>");
+    }
+
+    #[test]
+    fn escaping() {
+        let element = div([
+            title_attr("\"a\" & 'b'\nc"),
+            attr("viewBox", "0 0 10 10"),
+            inert(true),
+        ])
+        .with((text("<b>'x'</b>\u{1}"), style_tag([]).with("a > b {}")));
+        assert_eq!(
+            element.to_xml(),
+            "<div title=\"&quot;a&quot; &amp; &apos;b&apos;&#xA;c\" viewBox=\"0 0 10 10\" \
+             inert=\"inert\">&lt;b&gt;&apos;x&apos;&lt;/b&gt;\u{FFFD}<style>a &gt; b {}</style></div>"
+        );
+    }
+
+    #[test]
+    fn cdata_and_processing_instructions() {
+        let element = fragment().with((
+            processing_instruction("xml-stylesheet", r#"href="a.css""#),
+            processing_instruction("page-break", ""),
+            script([]).with(cdata("a < b && c]]>")),
+            p([]).with(cdata("a < b")),
+        ));
+        assert_eq!(
+            element.to_xml(),
+            r#"<?xml-stylesheet href="a.css"?><?page-break?><script><![CDATA[a < b && c]]]]><![CDATA[>]]></script><p><![CDATA[a < b]]></p>"#
+        );
+        assert_eq!(
+            element.to_html(),
+            r#"<?xml-stylesheet href="a.css"?><?page-break?><script>a < b && c]]></script><p>a &lt; b</p>"#
+        );
+    }
+
+    #[test]
+    #[should_panic = "invalid processing instruction target"]
+    fn reserved_processing_instruction_target() {
+        processing_instruction("XML", "version=\"1.0\"");
+    }
+
+    #[test]
+    #[should_panic = "must not contain"]
+    fn processing_instruction_data() {
+        processing_instruction("php", "echo 1; ?> <?php");
+    }
+
+    #[test]
+    fn processing_instructions_in_html() {
+        let data = "a ><script>alert(1)</script>";
+        let result =
+            std::panic::catch_unwind(|| div([]).with(processing_instruction("x", data)).to_html());
+        assert!(result.is_err());
+        assert_eq!(
+            div([])
+                .with(processing_instruction("x", "a < b"))
+                .to_html_minified(),
+            "<div><?x a < b?></div>"
+        );
+    }
+
+    #[test]
+    fn prolog() {
+        let doc = document().with(custom_tag("svg", []));
+        assert_eq!(
+            doc.to_xml(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><svg/>"#
+        );
+        assert_eq!(
+            doc.to_xml_with(&XmlOptions::xhtml().declaration(false)),
+            "<!DOCTYPE html><svg/>"
+        );
+        assert_eq!(
+            doc.to_xml_with(&XmlOptions::default().doctype(
+                r#"svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd""#
+            )),
+            r#"<?xml version="1.0" encoding="UTF-8"?><!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd"><svg/>"#
+        );
+        assert_eq!(doc.to_html(), "<!doctype html><svg></svg>");
+    }
+}